@@ -117,6 +117,9 @@
 #[cfg(not(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "loongarch64",
+    target_arch = "riscv32",
+    target_arch = "riscv64",
     target_arch = "x86",
     target_arch = "x86_64"
 )))]
@@ -137,7 +140,10 @@ macro_rules! eficall_abi {
 }
 
 // XXX: Rust does not define aapcs64, yet. Once it does, we should switch to it, rather than
-//      referring to the system default.
+//      referring to the system default. Note that there is nothing to probe for: no rustc
+//      release accepts `extern "aapcs64"`, so a build-time selection would always pick the
+//      fallback. The C ABI of `aarch64-unknown-uefi` (and all other non-apple aarch64 targets)
+//      is aapcs64, so this matches the specification as long as you build for a UEFI target.
 #[cfg(target_arch = "aarch64")]
 #[macro_export]
 #[doc(hidden)]
@@ -145,6 +151,24 @@ macro_rules! eficall_abi {
     (($($prefix:tt)*),($($suffix:tt)*)) => { $($prefix)* extern "C" $($suffix)* };
 }
 
+// The LoongArch UEFI ABI is the standard LoongArch C calling convention, which is the system
+// default and thus available via `extern "C"`.
+#[cfg(target_arch = "loongarch64")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eficall_abi {
+    (($($prefix:tt)*),($($suffix:tt)*)) => { $($prefix)* extern "C" $($suffix)* };
+}
+
+// XXX: Rust has no dedicated RISC-V calling-convention annotation. The standard RISC-V C calling
+//      convention is the system default, so we refer to it via `extern "C"`.
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! eficall_abi {
+    (($($prefix:tt)*),($($suffix:tt)*)) => { $($prefix)* extern "C" $($suffix)* };
+}
+
 #[cfg(target_arch = "x86")]
 #[macro_export]
 #[doc(hidden)]
@@ -162,6 +186,9 @@ macro_rules! eficall_abi {
 #[cfg(not(any(
     target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "loongarch64",
+    target_arch = "riscv32",
+    target_arch = "riscv64",
     target_arch = "x86",
     target_arch = "x86_64"
 )))]
@@ -239,6 +266,8 @@ macro_rules! eficall_abi {
 ///  * ia-64: The "P64 C Calling Convention" as described in the
 ///           "Itanium Software Conventions and Runtime Architecture Guide". It is also
 ///           standardized in the "Intel Itanium SAL Specification".
+///  * LoongArch: The standard LoongArch C calling convention is used, as described in the
+///               "LoongArch ELF ABI Specification".
 ///  * RISC-V: The "Standard RISC-V C Calling Convention" is used. The UEFI specification
 ///            describes it in detail, but also refers to the official RISC-V resources for
 ///            detailed information.
@@ -287,6 +316,65 @@ macro_rules! eficall {
     ($($arg:tt)*) => { eficall!{@munch((),($($arg)*))} };
 }
 
+/// Guid Literal
+///
+/// The `guid!()` macro creates a `Guid` from its canonical string representation, as used
+/// throughout the UEFI specification (e.g., `"8be4df61-93ca-11d2-aa0d-00e098032b8c"`). Both lower-
+/// and upper-case hexadecimal digits are accepted. The string is parsed at compile time, so the
+/// macro can be used to initialize constants and statics.
+///
+/// Malformed strings are rejected at compile time. The error message states what is wrong with
+/// the string (wrong length, misplaced hyphens, or invalid hexadecimal digits).
+///
+/// # Examples
+///
+/// ```
+/// use r_efi::{efi, guid};
+///
+/// const GLOBAL_VARIABLE: efi::Guid = guid!("8be4df61-93ca-11d2-aa0d-00e098032b8c");
+///
+/// assert_eq!(
+///     GLOBAL_VARIABLE,
+///     efi::Guid::from_fields(
+///         0x8be4df61,
+///         0x93ca,
+///         0x11d2,
+///         0xaa,
+///         0x0d,
+///         &[0x00, 0xe0, 0x98, 0x03, 0x2b, 0x8c],
+///     ),
+/// );
+/// ```
+///
+/// Strings of the wrong length fail to compile:
+///
+/// ```compile_fail,E0080
+/// let _ = r_efi::guid!("8be4df61-93ca-11d2-aa0d-00e098032b8");
+/// ```
+///
+/// So do strings with missing or misplaced hyphens:
+///
+/// ```compile_fail,E0080
+/// let _ = r_efi::guid!("8be4df6193ca-11d2-aa0d-00e098032b8c-");
+/// ```
+///
+/// And strings with invalid hexadecimal digits:
+///
+/// ```compile_fail,E0080
+/// let _ = r_efi::guid!("8be4df61-93ca-11d2-aa0d-00e098032b8g");
+/// ```
+//
+// XXX: The doctests above only verify that compilation fails with E0080. The panic message shown
+//      in the diagnostic is not checked, since that requires a UI-test harness like `trybuild`.
+#[macro_export]
+macro_rules! guid {
+    ($s:expr) => {{
+        // Force evaluation at compile time, so malformed strings are compile-time errors.
+        const GUID: $crate::base::Guid = $crate::base::Guid::from_str_const($s);
+        GUID
+    }};
+}
+
 /// Boolean Type
 ///
 /// This boolean type works very similar to the rust primitive type of [`bool`]. However, the rust
@@ -413,7 +501,7 @@ pub type ImageEntryPoint = fn(Handle, *mut crate::system::SystemTable) -> Status
 /// The individual fields are encoded as little-endian. Accessors are provided for the Guid
 /// structure allowing access to these fields in native endian byte order.
 #[repr(C, align(8))]
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq)]
 pub struct Guid {
     time_low: [u8; 4],
     time_mid: [u8; 2],
@@ -423,6 +511,48 @@ pub struct Guid {
     node: [u8; 6],
 }
 
+/// Media Access Control Address
+///
+/// The `MacAddress` type represents network hardware addresses. UEFI reserves 32 bytes for any
+/// kind of hardware address, even though the actual address is usually much shorter (e.g., 6 bytes
+/// for ethernet). Unused trailing bytes are zero.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MacAddress {
+    pub addr: [u8; 32],
+}
+
+/// IPv4 Address
+///
+/// The `Ipv4Address` type represents a 4-byte IPv4 address in network byte order.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ipv4Address {
+    pub addr: [u8; 4],
+}
+
+/// IPv6 Address
+///
+/// The `Ipv6Address` type represents a 16-byte IPv6 address in network byte order.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ipv6Address {
+    pub addr: [u8; 16],
+}
+
+/// IP Address
+///
+/// The `IpAddress` type is a union of IPv4 and IPv6 addresses. It is used wherever the IP version
+/// is only known at runtime. Note that this type is 4-byte aligned, as defined by the
+/// specification.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union IpAddress {
+    pub addr: [u32; 4],
+    pub v4: Ipv4Address,
+    pub v6: Ipv6Address,
+}
+
 impl Boolean {
     /// Literal False
     ///
@@ -433,6 +563,41 @@ impl Boolean {
     ///
     /// This constant represents the `true` value of the `Boolean` type.
     pub const TRUE: Boolean = Boolean(1u8);
+
+    /// Create Boolean from Integer
+    ///
+    /// This wraps the raw byte of a `BOOLEAN` field. Firmware is known to store values other than
+    /// 0 and 1 in such fields, so the byte is kept as is, and any non-zero value is treated as
+    /// `true`. This is the `const` counterpart of `From<u8>`.
+    pub const fn from_u8(v: u8) -> Boolean {
+        Boolean(v)
+    }
+
+    /// Check for Truth
+    ///
+    /// This returns `true` for any non-zero value, and `false` for 0. It matches the conversion
+    /// to `bool`, but can be used in `const` context.
+    pub const fn is_true(&self) -> bool {
+        self.0 != 0
+    }
+
+    /// Toggle Value
+    ///
+    /// This inverts the value in place. Any non-zero value is considered `true` and thus becomes
+    /// `FALSE`, while `FALSE` becomes `TRUE`.
+    pub fn toggle(&mut self) {
+        *self = !*self;
+    }
+}
+
+// Negation follows the same rules as the conversion to `bool`, so any non-zero value is inverted
+// to `FALSE`. The result is always one of the two canonical literals.
+impl core::ops::Not for Boolean {
+    type Output = Boolean;
+
+    fn not(self) -> Boolean {
+        (!<bool as From<Boolean>>::from(self)).into()
+    }
 }
 
 impl From<u8> for Boolean {
@@ -452,10 +617,7 @@ impl From<bool> for Boolean {
 
 impl From<Boolean> for bool {
     fn from(v: Boolean) -> Self {
-        match v.0 {
-            0 => false,
-            _ => true,
-        }
+        v.0 != 0
     }
 }
 
@@ -545,12 +707,17 @@ impl Status {
         self.0
     }
 
-    fn value(&self) -> usize {
-        self.0
+    /// Return Status Code without Error Bit
+    ///
+    /// This returns the numeric code of the status, with the error bit cleared. That is, errors
+    /// and warnings with the same number yield the same value (e.g., `BUFFER_TOO_SMALL` and
+    /// `WARN_STALE_DATA` both yield 5). Use `as_usize()` to get the raw representation.
+    pub const fn value(&self) -> usize {
+        self.0 & !Status::ERROR_MASK
     }
 
     fn mask(&self) -> usize {
-        self.value() & Status::MASK
+        self.0 & Status::MASK
     }
 
     /// Check whether this is an error
@@ -569,7 +736,81 @@ impl Status {
     /// operation returns with a warning code, it must be considered successfull, and the output
     /// parameters are valid.
     pub fn is_warning(&self) -> bool {
-        self.value() != 0 && self.mask() == Status::WARNING_MASK
+        self.0 != 0 && self.mask() == Status::WARNING_MASK
+    }
+
+    /// Check whether this is success
+    ///
+    /// This returns true if the given status code is exactly `SUCCESS`. Note that warnings are
+    /// considered success as well, but are not reported by this function. Use `!is_error()` to
+    /// include warnings.
+    pub fn is_success(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Return Name of Status Code
+    ///
+    /// This returns the name of the status code as used in the specification (e.g.,
+    /// `EFI_INVALID_PARAMETER`). If the status code is not one of the predefined codes, `None` is
+    /// returned. Note that the predefined error codes depend on the width of `usize`, and so does
+    /// this lookup.
+    pub fn name(&self) -> Option<&'static str> {
+        match *self {
+            Status::SUCCESS => Some("EFI_SUCCESS"),
+
+            Status::LOAD_ERROR => Some("EFI_LOAD_ERROR"),
+            Status::INVALID_PARAMETER => Some("EFI_INVALID_PARAMETER"),
+            Status::UNSUPPORTED => Some("EFI_UNSUPPORTED"),
+            Status::BAD_BUFFER_SIZE => Some("EFI_BAD_BUFFER_SIZE"),
+            Status::BUFFER_TOO_SMALL => Some("EFI_BUFFER_TOO_SMALL"),
+            Status::NOT_READY => Some("EFI_NOT_READY"),
+            Status::DEVICE_ERROR => Some("EFI_DEVICE_ERROR"),
+            Status::WRITE_PROTECTED => Some("EFI_WRITE_PROTECTED"),
+            Status::OUT_OF_RESOURCES => Some("EFI_OUT_OF_RESOURCES"),
+            Status::VOLUME_CORRUPTED => Some("EFI_VOLUME_CORRUPTED"),
+            Status::VOLUME_FULL => Some("EFI_VOLUME_FULL"),
+            Status::NO_MEDIA => Some("EFI_NO_MEDIA"),
+            Status::MEDIA_CHANGED => Some("EFI_MEDIA_CHANGED"),
+            Status::NOT_FOUND => Some("EFI_NOT_FOUND"),
+            Status::ACCESS_DENIED => Some("EFI_ACCESS_DENIED"),
+            Status::NO_RESPONSE => Some("EFI_NO_RESPONSE"),
+            Status::NO_MAPPING => Some("EFI_NO_MAPPING"),
+            Status::TIMEOUT => Some("EFI_TIMEOUT"),
+            Status::NOT_STARTED => Some("EFI_NOT_STARTED"),
+            Status::ALREADY_STARTED => Some("EFI_ALREADY_STARTED"),
+            Status::ABORTED => Some("EFI_ABORTED"),
+            Status::ICMP_ERROR => Some("EFI_ICMP_ERROR"),
+            Status::TFTP_ERROR => Some("EFI_TFTP_ERROR"),
+            Status::PROTOCOL_ERROR => Some("EFI_PROTOCOL_ERROR"),
+            Status::INCOMPATIBLE_VERSION => Some("EFI_INCOMPATIBLE_VERSION"),
+            Status::SECURITY_VIOLATION => Some("EFI_SECURITY_VIOLATION"),
+            Status::CRC_ERROR => Some("EFI_CRC_ERROR"),
+            Status::END_OF_MEDIA => Some("EFI_END_OF_MEDIA"),
+            Status::END_OF_FILE => Some("EFI_END_OF_FILE"),
+            Status::INVALID_LANGUAGE => Some("EFI_INVALID_LANGUAGE"),
+            Status::COMPROMISED_DATA => Some("EFI_COMPROMISED_DATA"),
+            Status::IP_ADDRESS_CONFLICT => Some("EFI_IP_ADDRESS_CONFLICT"),
+            Status::HTTP_ERROR => Some("EFI_HTTP_ERROR"),
+
+            Status::WARN_UNKNOWN_GLYPH => Some("EFI_WARN_UNKNOWN_GLYPH"),
+            Status::WARN_DELETE_FAILURE => Some("EFI_WARN_DELETE_FAILURE"),
+            Status::WARN_WRITE_FAILURE => Some("EFI_WARN_WRITE_FAILURE"),
+            Status::WARN_BUFFER_TOO_SMALL => Some("EFI_WARN_BUFFER_TOO_SMALL"),
+            Status::WARN_STALE_DATA => Some("EFI_WARN_STALE_DATA"),
+            Status::WARN_FILE_SYSTEM => Some("EFI_WARN_FILE_SYSTEM"),
+            Status::WARN_RESET_REQUIRED => Some("EFI_WARN_RESET_REQUIRED"),
+
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Status {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:#x}", self.0),
+        }
     }
 }
 
@@ -608,6 +849,63 @@ impl Guid {
         (bytes[0] as u16) | ((bytes[1] as u16) << 8)
     }
 
+    const fn hex_digit(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid GUID string: expected hexadecimal digit"),
+        }
+    }
+
+    const fn hex_byte(s: &[u8], i: usize) -> u8 {
+        (Self::hex_digit(s[i]) << 4) | Self::hex_digit(s[i + 1])
+    }
+
+    /// Parse a Guid from its canonical string representation
+    ///
+    /// This parses the 36-character representation used throughout the UEFI specification
+    /// (e.g., `"8be4df61-93ca-11d2-aa0d-00e098032b8c"`). Both lower- and upper-case hexadecimal
+    /// digits are accepted. The first three fields are given in native endianness, just like with
+    /// `from_fields()`.
+    ///
+    /// Malformed input panics. When evaluated in a `const` context, this turns into a
+    /// compile-time error. The `guid!()` macro wraps this function and guarantees compile-time
+    /// evaluation.
+    pub const fn from_str_const(s: &str) -> Guid {
+        let s = s.as_bytes();
+
+        if s.len() != 36 {
+            panic!("invalid GUID string: expected 36 characters");
+        }
+        if s[8] != b'-' || s[13] != b'-' || s[18] != b'-' || s[23] != b'-' {
+            panic!("invalid GUID string: expected '-' at positions 8, 13, 18, and 23");
+        }
+
+        // The textual representation lists the first three fields in native endianness (i.e.,
+        // most significant byte first), so reverse them into little-endian storage.
+        Guid {
+            time_low: [
+                Self::hex_byte(s, 6),
+                Self::hex_byte(s, 4),
+                Self::hex_byte(s, 2),
+                Self::hex_byte(s, 0),
+            ],
+            time_mid: [Self::hex_byte(s, 11), Self::hex_byte(s, 9)],
+            time_hi_and_version: [Self::hex_byte(s, 16), Self::hex_byte(s, 14)],
+            clk_seq_hi_res: Self::hex_byte(s, 19),
+            clk_seq_low: Self::hex_byte(s, 21),
+            node: [
+                Self::hex_byte(s, 24),
+                Self::hex_byte(s, 26),
+                Self::hex_byte(s, 28),
+                Self::hex_byte(s, 30),
+                Self::hex_byte(s, 32),
+                Self::hex_byte(s, 34),
+            ],
+        }
+    }
+
     /// Initialize a Guid from its individual fields
     ///
     /// This function initializes a Guid object given the individual fields as specified in the
@@ -628,12 +926,57 @@ impl Guid {
             time_low: Self::u32_to_bytes_le(time_low),
             time_mid: Self::u16_to_bytes_le(time_mid),
             time_hi_and_version: Self::u16_to_bytes_le(time_hi_and_version),
-            clk_seq_hi_res: clk_seq_hi_res,
-            clk_seq_low: clk_seq_low,
+            clk_seq_hi_res,
+            clk_seq_low,
             node: *node,
         }
     }
 
+    /// Initialize a Guid from its raw byte representation
+    ///
+    /// This is the inverse of `as_bytes()`. The bytes are taken in storage order, exactly as they
+    /// appear on the wire. No endianness conversion is performed.
+    pub const fn from_bytes(bytes: &[u8; 16]) -> Guid {
+        Guid {
+            time_low: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            time_mid: [bytes[4], bytes[5]],
+            time_hi_and_version: [bytes[6], bytes[7]],
+            clk_seq_hi_res: bytes[8],
+            clk_seq_low: bytes[9],
+            node: [
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            ],
+        }
+    }
+
+    /// Create a Random Guid from Entropy
+    ///
+    /// This creates a version-4 (random) Guid as defined in RFC 4122 from 16 bytes of entropy.
+    /// The bytes are used in storage order, and the version and variant bits are then overridden,
+    /// leaving 122 bits of entropy. The quality of the result depends solely on the entropy
+    /// provided by the caller.
+    pub const fn new_v4_from_bytes(bytes: [u8; 16]) -> Guid {
+        Guid {
+            time_low: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            time_mid: [bytes[4], bytes[5]],
+            // The version is stored in the high nibble of the little-endian field.
+            time_hi_and_version: [bytes[6], (bytes[7] & 0x0f) | 0x40],
+            clk_seq_hi_res: (bytes[8] & 0x3f) | 0x80,
+            clk_seq_low: bytes[9],
+            node: [
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            ],
+        }
+    }
+
+    /// Copy a Guid
+    ///
+    /// This returns a copy of the Guid. Unlike `Clone::clone()`, this can be used in `const`
+    /// context, for instance to derive a constant from another one.
+    pub const fn copy(&self) -> Guid {
+        *self
+    }
+
     /// Access a Guid as individual fields
     ///
     /// This decomposes a Guid back into the individual fields as given in the specification. The
@@ -656,6 +999,74 @@ impl Guid {
     pub fn as_bytes(&self) -> &[u8; 16] {
         unsafe { core::mem::transmute::<&Guid, &[u8; 16]>(self) }
     }
+
+    /// Format Short Form of a Guid
+    ///
+    /// This returns a formatter that prints only the `time_low` field of the Guid as 8 lower-case
+    /// hex digits (e.g., `c12a7328`). The field is printed in native endianness, just like in the
+    /// canonical representation of Guids. This is useful to keep logs compact, if the prefix is
+    /// enough to identify a Guid.
+    pub fn short(&self) -> impl core::fmt::Display {
+        struct Short(u32);
+
+        impl core::fmt::Display for Short {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "{:08x}", self.0)
+            }
+        }
+
+        Short(self.as_fields().0)
+    }
+
+    /// Access a Guid as mutable raw byte array
+    ///
+    /// This is the mutable counterpart of `as_bytes()`. It is a simple re-interpretation of the
+    /// Guid value as a 128-bit byte array. No endianness conversion is performed, the bytes are
+    /// in storage order just like for `from_bytes()`.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8; 16] {
+        unsafe { core::mem::transmute::<&mut Guid, &mut [u8; 16]>(self) }
+    }
+}
+
+// Guids are formatted in their canonical registry format (e.g.,
+// `8be4df61-93ca-11d2-aa0d-00e098032b8c`). The first three fields are printed in native
+// endianness, the remaining bytes in storage order. `Debug` uses the same format, since the
+// individual fields are meaningless to readers.
+impl core::fmt::Display for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let (time_low, time_mid, time_hi_and_version, clk_seq_hi_res, clk_seq_low, node) =
+            self.as_fields();
+
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-",
+            time_low, time_mid, time_hi_and_version, clk_seq_hi_res, clk_seq_low,
+        )?;
+        for v in node {
+            write!(f, "{:02x}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+// The byte-slice view of a Guid is its wire representation, as returned by `as_bytes()`. That is,
+// the individual fields are in little-endian order.
+impl AsRef<[u8]> for Guid {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsMut<[u8]> for Guid {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_bytes_mut()
+    }
 }
 
 #[cfg(test)]
@@ -663,6 +1074,25 @@ mod tests {
     use super::*;
     use std::mem::{align_of, size_of};
 
+    // Static ABI Assertions
+    //
+    // Same as `type_size_and_alignment()`, but evaluated at compile-time, so a layout change
+    // breaks the build of the test-suite rather than a single test.
+    const _: () = {
+        assert!(size_of::<Boolean>() == 1);
+        assert!(align_of::<Boolean>() == 1);
+        assert!(size_of::<Char8>() == 1);
+        assert!(size_of::<Char16>() == 2);
+        assert!(size_of::<Status>() == size_of::<usize>());
+        assert!(size_of::<Guid>() == 16);
+        assert!(align_of::<Guid>() == 8);
+        assert!(size_of::<MacAddress>() == 32);
+        assert!(size_of::<Ipv4Address>() == 4);
+        assert!(size_of::<Ipv6Address>() == 16);
+        assert!(size_of::<IpAddress>() == 16);
+        assert!(align_of::<IpAddress>() == 4);
+    };
+
     // Verify Type Size and Alignemnt
     //
     // Since UEFI defines explicitly the ABI of their types, we can verify that our implementation
@@ -743,6 +1173,237 @@ mod tests {
 
         assert_eq!(size_of::<Guid>(), 16);
         assert_eq!(align_of::<Guid>(), 8);
+
+        //
+        // MacAddress / Ipv4Address / Ipv6Address / IpAddress
+        //
+
+        assert_eq!(size_of::<MacAddress>(), 32);
+        assert_eq!(align_of::<MacAddress>(), 1);
+        assert_eq!(size_of::<Ipv4Address>(), 4);
+        assert_eq!(align_of::<Ipv4Address>(), 1);
+        assert_eq!(size_of::<Ipv6Address>(), 16);
+        assert_eq!(align_of::<Ipv6Address>(), 1);
+        assert_eq!(size_of::<IpAddress>(), 16);
+        assert_eq!(align_of::<IpAddress>(), 4);
+    }
+
+    // Verify Status Names
+    //
+    // The predefined status codes have their names as defined by the specification. Unknown
+    // codes are printed as hex integers. The error bit depends on the width of `usize`, so we
+    // verify the names are looked up with the correct encoding.
+    #[test]
+    fn status_names() {
+        let error_bit = 1usize << (usize::BITS - 1);
+
+        assert_eq!(format!("{}", Status::SUCCESS), "EFI_SUCCESS");
+        assert_eq!(
+            format!("{}", Status::from_usize(2 | error_bit)),
+            "EFI_INVALID_PARAMETER",
+        );
+        assert_eq!(
+            format!("{}", Status::from_usize(5 | error_bit)),
+            "EFI_BUFFER_TOO_SMALL",
+        );
+        assert_eq!(
+            format!("{}", Status::from_usize(4)),
+            "EFI_WARN_BUFFER_TOO_SMALL",
+        );
+
+        // Without the error bit, an error code is unknown. Similarly, 32-bit error codes do not
+        // match on 64-bit machines and vice versa.
+        assert_eq!(format!("{}", Status::from_usize(0x1234)), "0x1234");
+        if size_of::<usize>() == 8 {
+            assert_eq!(format!("{}", Status::from_usize(0x80000005)), "0x80000005");
+        } else {
+            assert_eq!(Status::from_usize(0x80000005), Status::BUFFER_TOO_SMALL);
+        }
+    }
+
+    // Verify Status Classification
+    //
+    // Errors, warnings, and success are distinguished by the error bit, which is the highest bit
+    // of `usize`. The numeric value of a status code excludes that bit.
+    #[test]
+    fn status_classes() {
+        let error_bit = 1usize << (usize::BITS - 1);
+
+        assert!(Status::SUCCESS.is_success());
+        assert!(!Status::SUCCESS.is_error());
+        assert!(!Status::SUCCESS.is_warning());
+
+        assert!(Status::NOT_FOUND.is_error());
+        assert!(!Status::NOT_FOUND.is_success());
+        assert!(!Status::NOT_FOUND.is_warning());
+        assert_eq!(Status::NOT_FOUND.as_usize(), 14 | error_bit);
+        assert_eq!(Status::NOT_FOUND.value(), 14);
+
+        assert!(Status::WARN_STALE_DATA.is_warning());
+        assert!(!Status::WARN_STALE_DATA.is_success());
+        assert!(!Status::WARN_STALE_DATA.is_error());
+        assert_eq!(Status::WARN_STALE_DATA.value(), 5);
+        assert_eq!(Status::BUFFER_TOO_SMALL.value(), 5);
+    }
+
+    // Verify Guid Formatting
+    //
+    // Guids are printed in their canonical registry format, with the first three fields in native
+    // endianness.
+    #[test]
+    fn guid_format() {
+        let guid = crate::system::GLOBAL_VARIABLE_GUID;
+
+        assert_eq!(format!("{}", guid), "8be4df61-93ca-11d2-aa0d-00e098032b8c");
+        assert_eq!(
+            format!("{:?}", guid),
+            "8be4df61-93ca-11d2-aa0d-00e098032b8c"
+        );
+        assert_eq!(
+            format!("{}", Guid::from_fields(0x1, 0x2, 0x3, 0x4, 0x5, &[0x6; 6])),
+            "00000001-0002-0003-0405-060606060606",
+        );
+    }
+
+    // Verify Guid Equality
+    //
+    // Guids compare and hash by all 16 bytes, so they can be used as keys in maps and sets.
+    #[test]
+    fn guid_hash() {
+        let a = Guid::from_fields(0x1, 0x2, 0x3, 0x4, 0x5, &[0x6, 0x7, 0x8, 0x9, 0xa, 0xb]);
+        let b = Guid::from_fields(0x1, 0x2, 0x3, 0x4, 0x5, &[0x6, 0x7, 0x8, 0x9, 0xa, 0xc]);
+
+        assert_eq!(a, a.copy());
+        assert_ne!(a, b);
+
+        let set: std::collections::HashSet<Guid> = [a, b, a].iter().copied().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&b));
+    }
+
+    // Verify Guid Byte Access
+    //
+    // The byte-slice view of a Guid must expose the wire representation, so it can be passed to
+    // generic byte consumers like hashers.
+    #[test]
+    fn guid_bytes() {
+        fn fnv1a(data: impl AsRef<[u8]>) -> u32 {
+            data.as_ref().iter().fold(0x811c9dc5u32, |h, b| {
+                (h ^ (*b as u32)).wrapping_mul(0x01000193)
+            })
+        }
+
+        let mut guid = Guid::from_fields(
+            0x01234567,
+            0x89ab,
+            0xcdef,
+            0x01,
+            0x23,
+            &[0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+        );
+        let wire = [
+            0x67, 0x45, 0x23, 0x01, 0xab, 0x89, 0xef, 0xcd, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab,
+            0xcd, 0xef,
+        ];
+
+        assert_eq!(guid.as_ref(), &wire[..]);
+        assert_eq!(fnv1a(guid), fnv1a(wire));
+        assert_eq!(Guid::from_bytes(&wire), guid);
+        assert_eq!(Guid::from_bytes(guid.as_bytes()), guid);
+
+        guid.as_mut()[0] = 0x00;
+        assert_eq!(guid.as_fields().0, 0x01234500);
+    }
+
+    #[test]
+    fn boolean_not() {
+        assert_eq!((!Boolean::FALSE).0, 1);
+        assert_eq!((!Boolean::TRUE).0, 0);
+        assert_eq!((!Boolean::from(0x80u8)).0, 0);
+
+        let mut v = Boolean::FALSE;
+        v.toggle();
+        assert!(v == true);
+        v.toggle();
+        assert!(v == false);
+    }
+
+    #[test]
+    fn guid_copy() {
+        const ORIGINAL: Guid = guid!("8be4df61-93ca-11d2-aa0d-00e098032b8c");
+        static COPY: Guid = ORIGINAL.copy();
+
+        assert_eq!(COPY, ORIGINAL);
+        assert_eq!(COPY.copy(), ORIGINAL.clone());
+
+        // Guids are plain values, so they can be moved into and out of tables by copy.
+        let table = [
+            ORIGINAL,
+            Guid::from_fields(0, 0, 0, 0, 0, &[0; 6]),
+            ORIGINAL,
+        ];
+        let [first, second, third] = table;
+        assert_eq!(first, third);
+        assert_ne!(first, second);
+        assert_eq!(table[0], ORIGINAL);
+    }
+
+    #[test]
+    fn guid_macro() {
+        let fields = (
+            0x8be4df61,
+            0x93ca,
+            0x11d2,
+            0xaa,
+            0x0d,
+            &[0x00, 0xe0, 0x98, 0x03, 0x2b, 0x8c],
+        );
+
+        assert_eq!(
+            guid!("8be4df61-93ca-11d2-aa0d-00e098032b8c").as_fields(),
+            fields
+        );
+        assert_eq!(
+            guid!("8BE4DF61-93CA-11D2-AA0D-00E098032B8C").as_fields(),
+            fields
+        );
+
+        const GUID: Guid = Guid::from_str_const("8be4df61-93CA-11d2-aa0D-00e098032b8c");
+        assert_eq!(GUID.as_fields(), fields);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 36 characters")]
+    fn guid_str_length() {
+        Guid::from_str_const("8be4df61-93ca-11d2-aa0d-00e098032b8c0");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected '-'")]
+    fn guid_str_hyphens() {
+        Guid::from_str_const("8be4df61-93ca-11d2aaa0d-00e098032b8c");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected hexadecimal digit")]
+    fn guid_str_digits() {
+        Guid::from_str_const("8be4df61-93ca-11d2-aa0d-00e098032b8g");
+    }
+
+    #[test]
+    fn guid_short() {
+        let guid = Guid::from_fields(
+            0xc12a7328,
+            0xf81f,
+            0x11d2,
+            0xba,
+            0x4b,
+            &[0x00, 0xa0, 0xc9, 0x3e, 0xc9, 0x3b],
+        );
+        assert_eq!(format!("{}", guid.short()), "c12a7328");
+
+        let guid = Guid::from_fields(0x0000abcd, 0, 0, 0, 0, &[0; 6]);
+        assert_eq!(format!("{}", guid.short()), "0000abcd");
     }
 
     #[test]
@@ -762,6 +1423,22 @@ mod tests {
         eficall! {pub unsafe fn _unused02() {}}
     }
 
+    // Verify that `eficall!{}` expands to `extern "C"` on all architectures where the UEFI
+    // calling convention is the C default. This is only compiled on these targets.
+    #[cfg(any(
+        target_arch = "loongarch64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    ))]
+    #[test]
+    fn eficall_c_abi() {
+        eficall! {fn identity(v: u32) -> u32 { v }}
+
+        let f: eficall! {fn(u32) -> u32} = identity;
+        let f: extern "C" fn(u32) -> u32 = f;
+        assert_eq!(f(71), 71);
+    }
+
     // Verify Boolean ABI
     //
     // Even though booleans are strictly 1-bit, and thus 0 or 1, in practice all UEFI systems
@@ -783,6 +1460,10 @@ mod tests {
             let v1: Boolean = i.into();
             let v2: Boolean = unsafe { std::mem::transmute::<u8, Boolean>(i) };
 
+            assert_eq!(v1, Boolean::from_u8(i));
+            assert_eq!(v1.is_true(), i != 0);
+            assert_eq!(bool::from(v2), i != 0);
+
             assert_eq!(v1, v2);
             assert_eq!(v1, v1);
             assert_eq!(v2, v2);
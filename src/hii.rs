@@ -0,0 +1,261 @@
+//! Human Interface Infrastructure (HII)
+//!
+//! This module contains bindings and definitions copied from Part III of the UEFI specification,
+//! which describes the Human Interface Infrastructure (HII). HII provides the data structures
+//! firmware uses to describe fonts, strings, images, forms, and keyboard layouts.
+//!
+//! Most of these structures are packed, as defined by the specification. Unlike the rest of the
+//! specification, HII data is designed to be stored in packages and thus uses byte-packing
+//! without any padding.
+
+//
+// Keyboard Layouts
+//
+// Keyboard layouts map physical keys (identified by their position on a standard keyboard, see
+// `Key`) to the unicode characters they produce, considering modifiers like shift or AltGr.
+// Layouts are stored in the HII database as keyboard packages.
+//
+
+/// Physical Keys
+///
+/// A key is identified by its physical position on a standard keyboard (the `EFI_KEY`
+/// enumeration of the specification). The rows are named `A` to `E`, starting at the bottom row
+/// next to the space bar, counting columns from left to right.
+///
+/// Since keyboard layouts are supplied by firmware, a key can carry any 32-bit value. Hence, this
+/// is a transparent wrapper of the raw value rather than an enum, with the keys defined by the
+/// specification provided as associated constants.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Key(u32);
+
+impl Key {
+    pub const LCTRL: Key = Key(0);
+    pub const A0: Key = Key(1);
+    pub const LALT: Key = Key(2);
+    pub const SPACE_BAR: Key = Key(3);
+    pub const A2: Key = Key(4);
+    pub const A3: Key = Key(5);
+    pub const A4: Key = Key(6);
+    pub const RCTRL: Key = Key(7);
+    pub const LEFT_ARROW: Key = Key(8);
+    pub const DOWN_ARROW: Key = Key(9);
+    pub const RIGHT_ARROW: Key = Key(10);
+    pub const ZERO: Key = Key(11);
+    pub const PERIOD: Key = Key(12);
+    pub const ENTER: Key = Key(13);
+    pub const LSHIFT: Key = Key(14);
+    pub const B0: Key = Key(15);
+    pub const B1: Key = Key(16);
+    pub const B2: Key = Key(17);
+    pub const B3: Key = Key(18);
+    pub const B4: Key = Key(19);
+    pub const B5: Key = Key(20);
+    pub const B6: Key = Key(21);
+    pub const B7: Key = Key(22);
+    pub const B8: Key = Key(23);
+    pub const B9: Key = Key(24);
+    pub const B10: Key = Key(25);
+    pub const RSHIFT: Key = Key(26);
+    pub const UP_ARROW: Key = Key(27);
+    pub const ONE: Key = Key(28);
+    pub const TWO: Key = Key(29);
+    pub const THREE: Key = Key(30);
+    pub const CAPS_LOCK: Key = Key(31);
+    pub const C1: Key = Key(32);
+    pub const C2: Key = Key(33);
+    pub const C3: Key = Key(34);
+    pub const C4: Key = Key(35);
+    pub const C5: Key = Key(36);
+    pub const C6: Key = Key(37);
+    pub const C7: Key = Key(38);
+    pub const C8: Key = Key(39);
+    pub const C9: Key = Key(40);
+    pub const C10: Key = Key(41);
+    pub const C11: Key = Key(42);
+    pub const C12: Key = Key(43);
+    pub const FOUR: Key = Key(44);
+    pub const FIVE: Key = Key(45);
+    pub const SIX: Key = Key(46);
+    pub const PLUS: Key = Key(47);
+    pub const TAB: Key = Key(48);
+    pub const D1: Key = Key(49);
+    pub const D2: Key = Key(50);
+    pub const D3: Key = Key(51);
+    pub const D4: Key = Key(52);
+    pub const D5: Key = Key(53);
+    pub const D6: Key = Key(54);
+    pub const D7: Key = Key(55);
+    pub const D8: Key = Key(56);
+    pub const D9: Key = Key(57);
+    pub const D10: Key = Key(58);
+    pub const D11: Key = Key(59);
+    pub const D12: Key = Key(60);
+    pub const D13: Key = Key(61);
+    pub const DEL: Key = Key(62);
+    pub const END: Key = Key(63);
+    pub const PG_DN: Key = Key(64);
+    pub const SEVEN: Key = Key(65);
+    pub const EIGHT: Key = Key(66);
+    pub const NINE: Key = Key(67);
+    pub const E0: Key = Key(68);
+    pub const E1: Key = Key(69);
+    pub const E2: Key = Key(70);
+    pub const E3: Key = Key(71);
+    pub const E4: Key = Key(72);
+    pub const E5: Key = Key(73);
+    pub const E6: Key = Key(74);
+    pub const E7: Key = Key(75);
+    pub const E8: Key = Key(76);
+    pub const E9: Key = Key(77);
+    pub const E10: Key = Key(78);
+    pub const E11: Key = Key(79);
+    pub const E12: Key = Key(80);
+    pub const BACK_SPACE: Key = Key(81);
+    pub const INS: Key = Key(82);
+    pub const HOME: Key = Key(83);
+    pub const PG_UP: Key = Key(84);
+    pub const NLCK: Key = Key(85);
+    pub const SLASH: Key = Key(86);
+    pub const ASTERISK: Key = Key(87);
+    pub const MINUS: Key = Key(88);
+    pub const ESC: Key = Key(89);
+    pub const F1: Key = Key(90);
+    pub const F2: Key = Key(91);
+    pub const F3: Key = Key(92);
+    pub const F4: Key = Key(93);
+    pub const F5: Key = Key(94);
+    pub const F6: Key = Key(95);
+    pub const F7: Key = Key(96);
+    pub const F8: Key = Key(97);
+    pub const F9: Key = Key(98);
+    pub const F10: Key = Key(99);
+    pub const F11: Key = Key(100);
+    pub const F12: Key = Key(101);
+    pub const PRINT: Key = Key(102);
+    pub const SLCK: Key = Key(103);
+    pub const PAUSE: Key = Key(104);
+    pub const INTL0: Key = Key(105);
+    pub const INTL1: Key = Key(106);
+    pub const INTL2: Key = Key(107);
+    pub const INTL3: Key = Key(108);
+    pub const INTL4: Key = Key(109);
+    pub const INTL5: Key = Key(110);
+    pub const INTL6: Key = Key(111);
+    pub const INTL7: Key = Key(112);
+    pub const INTL8: Key = Key(113);
+    pub const INTL9: Key = Key(114);
+
+    /// Create Key from Integer
+    ///
+    /// This takes the raw value of a key as stored in keyboard layouts.
+    pub const fn from_u32(v: u32) -> Key {
+        Key(v)
+    }
+
+    /// Return Underlying Integer Representation
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+pub const NULL_MODIFIER: u16 = 0x0000u16;
+pub const LEFT_CONTROL_MODIFIER: u16 = 0x0001u16;
+pub const RIGHT_CONTROL_MODIFIER: u16 = 0x0002u16;
+pub const LEFT_ALT_MODIFIER: u16 = 0x0003u16;
+pub const RIGHT_ALT_MODIFIER: u16 = 0x0004u16;
+pub const ALT_GR_MODIFIER: u16 = 0x0005u16;
+pub const INSERT_MODIFIER: u16 = 0x0006u16;
+pub const DELETE_MODIFIER: u16 = 0x0007u16;
+pub const PAGE_DOWN_MODIFIER: u16 = 0x0008u16;
+pub const PAGE_UP_MODIFIER: u16 = 0x0009u16;
+pub const HOME_MODIFIER: u16 = 0x000au16;
+pub const END_MODIFIER: u16 = 0x000bu16;
+pub const LEFT_SHIFT_MODIFIER: u16 = 0x000cu16;
+pub const RIGHT_SHIFT_MODIFIER: u16 = 0x000du16;
+pub const CAPS_LOCK_MODIFIER: u16 = 0x000eu16;
+pub const NUM_LOCK_MODIFIER: u16 = 0x000fu16;
+pub const LEFT_ARROW_MODIFIER: u16 = 0x0010u16;
+pub const RIGHT_ARROW_MODIFIER: u16 = 0x0011u16;
+pub const DOWN_ARROW_MODIFIER: u16 = 0x0012u16;
+pub const UP_ARROW_MODIFIER: u16 = 0x0013u16;
+pub const NS_KEY_MODIFIER: u16 = 0x0014u16;
+pub const NS_KEY_DEPENDENCY_MODIFIER: u16 = 0x0015u16;
+pub const FUNCTION_KEY_ONE_MODIFIER: u16 = 0x0016u16;
+pub const FUNCTION_KEY_TWO_MODIFIER: u16 = 0x0017u16;
+pub const FUNCTION_KEY_THREE_MODIFIER: u16 = 0x0018u16;
+pub const FUNCTION_KEY_FOUR_MODIFIER: u16 = 0x0019u16;
+pub const FUNCTION_KEY_FIVE_MODIFIER: u16 = 0x001au16;
+pub const FUNCTION_KEY_SIX_MODIFIER: u16 = 0x001bu16;
+pub const FUNCTION_KEY_SEVEN_MODIFIER: u16 = 0x001cu16;
+pub const FUNCTION_KEY_EIGHT_MODIFIER: u16 = 0x001du16;
+pub const FUNCTION_KEY_NINE_MODIFIER: u16 = 0x001eu16;
+pub const FUNCTION_KEY_TEN_MODIFIER: u16 = 0x001fu16;
+pub const FUNCTION_KEY_ELEVEN_MODIFIER: u16 = 0x0020u16;
+pub const FUNCTION_KEY_TWELVE_MODIFIER: u16 = 0x0021u16;
+pub const PRINT_MODIFIER: u16 = 0x0022u16;
+pub const SYS_REQUEST_MODIFIER: u16 = 0x0023u16;
+pub const SCROLL_LOCK_MODIFIER: u16 = 0x0024u16;
+pub const PAUSE_MODIFIER: u16 = 0x0025u16;
+pub const BREAK_MODIFIER: u16 = 0x0026u16;
+pub const LEFT_LOGO_MODIFIER: u16 = 0x0027u16;
+pub const RIGHT_LOGO_MODIFIER: u16 = 0x0028u16;
+pub const MENU_MODIFIER: u16 = 0x0029u16;
+
+pub const AFFECTED_BY_STANDARD_SHIFT: u16 = 0x0001u16;
+pub const AFFECTED_BY_CAPS_LOCK: u16 = 0x0002u16;
+pub const AFFECTED_BY_NUM_LOCK: u16 = 0x0004u16;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct KeyDescriptor {
+    pub key: Key,
+    pub unicode: crate::base::Char16,
+    pub shifted_unicode: crate::base::Char16,
+    pub alt_gr_unicode: crate::base::Char16,
+    pub shifted_alt_gr_unicode: crate::base::Char16,
+    pub modifier: u16,
+    pub affected_attribute: u16,
+}
+
+/// Keyboard Layout
+///
+/// This is the header of a keyboard layout (`EFI_HII_KEYBOARD_LAYOUT`), followed by its key
+/// descriptors. The layout is followed by a description-string bundle, which is located at
+/// `layout_descriptor_string_offset` bytes from the start of the layout.
+///
+/// Since the structure is packed, the Guid cannot be embedded as `Guid` type. Instead, its raw
+/// wire representation is stored. The `N` parameter allows accessing the descriptor array with a
+/// compile-time fixed size. It defaults to `0`, in which case only the header is accessible.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct KeyboardLayout<const N: usize = 0> {
+    pub layout_length: u16,
+    pub guid: [u8; 16],
+    pub layout_descriptor_string_offset: u32,
+    pub descriptor_count: u8,
+    pub descriptors: [KeyDescriptor; N],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+
+    // Verify Keyboard Layout Packing
+    //
+    // HII structures are byte-packed. Verify the keyboard-layout structures have no padding.
+    #[test]
+    fn keyboard_layout_packing() {
+        assert_eq!(size_of::<Key>(), 4);
+        assert_eq!(size_of::<KeyDescriptor>(), 16);
+        assert_eq!(align_of::<KeyDescriptor>(), 1);
+        assert_eq!(size_of::<KeyboardLayout>(), 23);
+        assert_eq!(size_of::<KeyboardLayout<2>>(), 23 + 2 * 16);
+        assert_eq!(align_of::<KeyboardLayout>(), 1);
+
+        assert_eq!(Key::PAUSE.as_u32(), 104);
+        assert_eq!(Key::INTL9, Key::from_u32(114));
+        assert_eq!(MENU_MODIFIER, 0x0029);
+    }
+}
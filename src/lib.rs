@@ -63,7 +63,13 @@
 // In case of unit-test compilation, we pull in `std` and drop the `no_std` marker. This allows
 // basic unit-tests on the compilation host. For integration tests, we have separate compilation
 // units, so they will be unaffected by this.
-#![cfg_attr(not(test), no_std)]
+//
+// The optional `std` feature drops the marker as well. It enables conversions to and from types
+// of the standard library, which is useful for tools running on the host.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+// Our documentation aligns continuation lines of list items with the item text, rather than with
+// the list marker. Clippy prefers the latter, but we keep the former for readability.
+#![allow(clippy::doc_overindented_list_items)]
 
 // Import the different core modules. We separate them into different modules to make it easier to
 // work on them and describe what each part implements. This is different to the reference
@@ -80,6 +86,22 @@ pub mod system;
 // not violates its own namespacing).
 pub mod protocols;
 
+// Import the Human Interface Infrastructure (HII). This is a separate part of the specification,
+// which provides data structures to describe forms, fonts, strings, and keyboard layouts.
+pub mod hii;
+
+// Import networking helpers. These are convenience types shared by the networking protocols, but
+// are not defined by the specification itself.
+pub mod net;
+
+// Import string helpers for the UCS-2 strings used by UEFI.
+pub mod string;
+
+// Host-side stand-ins for the firmware tables. They allow unit-testing the helpers that call into
+// firmware. Other crates can use them for their own tests via the `test-utils` feature.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
+
 /// Flat EFI Namespace
 ///
 /// The EFI namespace re-exports all symbols in a single, flat namespace. This allows mirroring
@@ -103,7 +125,11 @@ pub mod efi {
     pub use crate::base::Guid;
     pub use crate::base::Handle;
     pub use crate::base::ImageEntryPoint;
+    pub use crate::base::IpAddress;
+    pub use crate::base::Ipv4Address;
+    pub use crate::base::Ipv6Address;
     pub use crate::base::Lba;
+    pub use crate::base::MacAddress;
     pub use crate::base::PhysicalAddress;
     pub use crate::base::Status;
     pub use crate::base::Tpl;
@@ -124,6 +150,7 @@ pub mod efi {
     pub use crate::system::VariableAuthentication3;
     pub use crate::system::VariableAuthentication3CertId;
     pub use crate::system::VariableAuthentication3Nonce;
+    pub use crate::system::GLOBAL_VARIABLE_GUID;
     pub use crate::system::HARDWARE_ERROR_VARIABLE_GUID;
     pub use crate::system::VARIABLE_APPEND_WRITE;
     pub use crate::system::VARIABLE_AUTHENTICATED_WRITE_ACCESS;
@@ -137,6 +164,14 @@ pub mod efi {
     pub use crate::system::VARIABLE_RUNTIME_ACCESS;
     pub use crate::system::VARIABLE_TIME_BASED_AUTHENTICATED_WRITE_ACCESS;
 
+    pub use crate::system::LoadOption;
+    pub use crate::system::LOAD_OPTION_ACTIVE;
+    pub use crate::system::LOAD_OPTION_CATEGORY;
+    pub use crate::system::LOAD_OPTION_CATEGORY_APP;
+    pub use crate::system::LOAD_OPTION_CATEGORY_BOOT;
+    pub use crate::system::LOAD_OPTION_FORCE_RECONNECT;
+    pub use crate::system::LOAD_OPTION_HIDDEN;
+
     pub use crate::system::OPTIONAL_POINTER;
 
     pub use crate::system::ResetType;
@@ -160,6 +195,7 @@ pub mod efi {
 
     pub use crate::system::EventNotify;
     pub use crate::system::TimerDelay;
+    pub use crate::system::EVENT_GROUP_AFTER_READY_TO_BOOT;
     pub use crate::system::EVENT_GROUP_EXIT_BOOT_SERVICES;
     pub use crate::system::EVENT_GROUP_MEMORY_MAP_CHANGE;
     pub use crate::system::EVENT_GROUP_READY_TO_BOOT;
@@ -185,6 +221,10 @@ pub mod efi {
     pub use crate::system::MEMORY_RO;
     pub use crate::system::MEMORY_RP;
     pub use crate::system::MEMORY_RUNTIME;
+    pub use crate::system::MEMORY_TYPE_OEM_RESERVED_MAX;
+    pub use crate::system::MEMORY_TYPE_OEM_RESERVED_MIN;
+    pub use crate::system::MEMORY_TYPE_OS_RESERVED_MAX;
+    pub use crate::system::MEMORY_TYPE_OS_RESERVED_MIN;
     pub use crate::system::MEMORY_UC;
     pub use crate::system::MEMORY_UCE;
     pub use crate::system::MEMORY_WB;
@@ -192,10 +232,13 @@ pub mod efi {
     pub use crate::system::MEMORY_WP;
     pub use crate::system::MEMORY_WT;
     pub use crate::system::MEMORY_XP;
+    pub use crate::system::PAGE_SIZE;
 
     pub use crate::system::InterfaceType;
     pub use crate::system::LocateSearchType;
     pub use crate::system::OpenProtocolInformationEntry;
+    pub use crate::system::BUS_SPECIFIC_DRIVER_OVERRIDE_PROTOCOL_GUID;
+    pub use crate::system::HOT_PLUG_DEVICE_GUID;
     pub use crate::system::OPEN_PROTOCOL_BY_CHILD_CONTROLLER;
     pub use crate::system::OPEN_PROTOCOL_BY_DRIVER;
     pub use crate::system::OPEN_PROTOCOL_BY_HANDLE_PROTOCOL;
@@ -204,13 +247,23 @@ pub mod efi {
     pub use crate::system::OPEN_PROTOCOL_TEST_PROTOCOL;
 
     pub use crate::system::ConfigurationTable;
+    pub use crate::system::ConfigurationTables;
     pub use crate::system::MemoryAttributesTable;
+    pub use crate::system::MemoryDescriptors;
     pub use crate::system::PropertiesTable;
+    pub use crate::system::ACPI_20_TABLE_GUID;
+    pub use crate::system::ACPI_TABLE_GUID;
+    pub use crate::system::DTB_TABLE_GUID;
+    pub use crate::system::MEMORY_ATTRIBUTES_FLAGS_RT_FORWARD_CONTROL_FLOW_GUARD;
     pub use crate::system::MEMORY_ATTRIBUTES_TABLE_GUID;
     pub use crate::system::MEMORY_ATTRIBUTES_TABLE_VERSION;
+    pub use crate::system::MPS_TABLE_GUID;
     pub use crate::system::PROPERTIES_RUNTIME_MEMORY_PROTECTION_NON_EXECUTABLE_PE_DATA;
     pub use crate::system::PROPERTIES_TABLE_GUID;
     pub use crate::system::PROPERTIES_TABLE_VERSION;
+    pub use crate::system::SAL_SYSTEM_TABLE_GUID;
+    pub use crate::system::SMBIOS3_TABLE_GUID;
+    pub use crate::system::SMBIOS_TABLE_GUID;
 
     pub use crate::system::BootServices;
     pub use crate::system::RuntimeServices;
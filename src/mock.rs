@@ -0,0 +1,450 @@
+//! Mock Firmware Tables
+//!
+//! This module provides stand-ins for the firmware tables, so helpers can be exercised on the
+//! compilation host. All function pointers of a mocked table refer to a trap that aborts when
+//! invoked. Callers are expected to replace the pointers they need with their own mocks.
+//!
+//! Additionally, an allocation tracker is provided, which implements the memory-allocation
+//! services in terms of the host allocator. It records all outstanding allocations, so tests can
+//! verify that the code under test releases everything it allocates.
+//!
+//! This module is used by the unit tests of this crate. It is also available to other crates via
+//! the `test-utils` feature, which requires the standard library of the host.
+
+eficall! {fn unimplemented() {
+    std::process::abort();
+}}
+
+/// Create Trap
+///
+/// This returns a trap for any function-pointer type. The trap aborts the process when invoked.
+/// This is meant to fill function-pointer slots of mocked tables, which are not used by a test.
+///
+/// # Safety
+///
+/// `T` must be a function-pointer type (optionally wrapped in `Option`).
+pub unsafe fn stub<T: Copy>() -> T {
+    let f = unimplemented as eficall! {fn()};
+
+    assert_eq!(core::mem::size_of::<T>(), core::mem::size_of_val(&f));
+    core::mem::transmute_copy::<eficall! {fn()}, T>(&f)
+}
+
+/// Create Mocked Boot Services
+///
+/// This creates a boot-services table with all function pointers set to traps.
+pub fn boot_services() -> crate::system::BootServices {
+    // All slots are function pointers, so they can be filled with traps.
+    unsafe {
+        crate::system::BootServices {
+            hdr: crate::system::TableHeader {
+                signature: crate::system::BOOT_SERVICES_SIGNATURE,
+                revision: crate::system::BOOT_SERVICES_REVISION,
+                header_size: core::mem::size_of::<crate::system::BootServices>() as u32,
+                crc32: 0,
+                reserved: 0,
+            },
+            raise_tpl: stub(),
+            restore_tpl: stub(),
+            allocate_pages: stub(),
+            free_pages: stub(),
+            get_memory_map: stub(),
+            allocate_pool: stub(),
+            free_pool: stub(),
+            create_event: stub(),
+            set_timer: stub(),
+            wait_for_event: stub(),
+            signal_event: stub(),
+            close_event: stub(),
+            check_event: stub(),
+            install_protocol_interface: stub(),
+            reinstall_protocol_interface: stub(),
+            uninstall_protocol_interface: stub(),
+            handle_protocol: stub(),
+            reserved: core::ptr::null_mut(),
+            register_protocol_notify: stub(),
+            locate_handle: stub(),
+            locate_device_path: stub(),
+            install_configuration_table: stub(),
+            load_image: stub(),
+            start_image: stub(),
+            exit: stub(),
+            unload_image: stub(),
+            exit_boot_services: stub(),
+            get_next_monotonic_count: stub(),
+            stall: stub(),
+            set_watchdog_timer: stub(),
+            connect_controller: stub(),
+            disconnect_controller: stub(),
+            open_protocol: stub(),
+            close_protocol: stub(),
+            open_protocol_information: stub(),
+            protocols_per_handle: stub(),
+            locate_handle_buffer: stub(),
+            locate_protocol: stub(),
+            install_multiple_protocol_interfaces: stub(),
+            uninstall_multiple_protocol_interfaces: stub(),
+            calculate_crc32: stub(),
+            copy_mem: stub(),
+            set_mem: stub(),
+            create_event_ex: stub(),
+        }
+    }
+}
+
+/// Create Mocked Runtime Services
+///
+/// This creates a runtime-services table with all function pointers set to traps.
+pub fn runtime_services() -> crate::system::RuntimeServices {
+    // All slots are function pointers, so they can be filled with traps.
+    unsafe {
+        crate::system::RuntimeServices {
+            hdr: crate::system::TableHeader {
+                signature: crate::system::RUNTIME_SERVICES_SIGNATURE,
+                revision: crate::system::RUNTIME_SERVICES_REVISION,
+                header_size: core::mem::size_of::<crate::system::RuntimeServices>() as u32,
+                crc32: 0,
+                reserved: 0,
+            },
+            get_time: stub(),
+            set_time: stub(),
+            get_wakeup_time: stub(),
+            set_wakeup_time: stub(),
+            set_virtual_address_map: stub(),
+            convert_pointer: stub(),
+            get_variable: stub(),
+            get_next_variable_name: stub(),
+            set_variable: stub(),
+            get_next_high_mono_count: stub(),
+            reset_system: stub(),
+            update_capsule: stub(),
+            query_capsule_capabilities: stub(),
+            query_variable_info: stub(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Kind {
+    Pool,
+    Pages(usize),
+}
+
+#[derive(Debug)]
+struct Allocation {
+    kind: Kind,
+    memory_type: crate::system::MemoryType,
+    layout: std::alloc::Layout,
+}
+
+std::thread_local! {
+    static ALLOCATIONS: std::cell::RefCell<Option<std::collections::BTreeMap<usize, Allocation>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+// Pool allocations are 8-byte aligned, as required by the specification.
+const POOL_ALIGNMENT: usize = 8;
+
+// The allocator services must not panic, since unwinding out of an `eficall!` function aborts.
+// Without an active tracker they fail with `NOT_READY` instead.
+fn active() -> bool {
+    ALLOCATIONS.with(|v| v.borrow().is_some())
+}
+
+fn track(address: usize, allocation: Allocation) {
+    ALLOCATIONS.with(|v| {
+        if let Some(v) = v.borrow_mut().as_mut() {
+            v.insert(address, allocation);
+        }
+    });
+}
+
+fn untrack(address: usize, kind: impl Fn(&Kind) -> bool) -> Option<Allocation> {
+    ALLOCATIONS.with(|v| {
+        let mut v = v.borrow_mut();
+        let v = v.as_mut()?;
+
+        match v.get(&address) {
+            Some(a) if kind(&a.kind) => v.remove(&address),
+            _ => None,
+        }
+    })
+}
+
+fn allocate(
+    kind: Kind,
+    memory_type: crate::system::MemoryType,
+    size: usize,
+    align: usize,
+) -> Option<usize> {
+    // The host allocator does not support zero-sized allocations, but firmware does.
+    let layout = std::alloc::Layout::from_size_align(size.max(1), align).ok()?;
+    let address = unsafe { std::alloc::alloc(layout) } as usize;
+
+    if address == 0 {
+        return None;
+    }
+
+    track(
+        address,
+        Allocation {
+            kind,
+            memory_type,
+            layout,
+        },
+    );
+    Some(address)
+}
+
+eficall! {fn allocate_pool(
+    memory_type: crate::system::MemoryType,
+    size: usize,
+    buffer: *mut *mut core::ffi::c_void,
+) -> crate::base::Status {
+    if !active() {
+        return crate::base::Status::NOT_READY;
+    }
+
+    match allocate(Kind::Pool, memory_type, size, POOL_ALIGNMENT) {
+        Some(address) => {
+            unsafe { *buffer = address as *mut core::ffi::c_void };
+            crate::base::Status::SUCCESS
+        }
+        None => crate::base::Status::OUT_OF_RESOURCES,
+    }
+}}
+
+eficall! {fn free_pool(buffer: *mut core::ffi::c_void) -> crate::base::Status {
+    if !active() {
+        return crate::base::Status::NOT_READY;
+    }
+
+    match untrack(buffer as usize, |k| matches!(k, Kind::Pool)) {
+        Some(a) => {
+            unsafe { std::alloc::dealloc(buffer as *mut u8, a.layout) };
+            crate::base::Status::SUCCESS
+        }
+        None => crate::base::Status::INVALID_PARAMETER,
+    }
+}}
+
+eficall! {fn allocate_pages(
+    r#type: crate::system::AllocateType,
+    memory_type: crate::system::MemoryType,
+    pages: usize,
+    memory: *mut crate::base::PhysicalAddress,
+) -> crate::base::Status {
+    if !active() {
+        return crate::base::Status::NOT_READY;
+    }
+
+    // Allocations at fixed addresses cannot be served by the host allocator.
+    let max = match r#type {
+        crate::system::AllocateType::AllocateAnyPages => u64::MAX,
+        crate::system::AllocateType::AllocateMaxAddress => unsafe { *memory },
+        crate::system::AllocateType::AllocateAddress => {
+            return crate::base::Status::UNSUPPORTED;
+        }
+    };
+
+    let size = match pages.checked_mul(crate::system::PAGE_SIZE) {
+        Some(v) => v,
+        None => return crate::base::Status::OUT_OF_RESOURCES,
+    };
+
+    match allocate(Kind::Pages(pages), memory_type, size, crate::system::PAGE_SIZE) {
+        Some(address) if (address + size - 1) as u64 <= max => {
+            unsafe { *memory = address as u64 };
+            crate::base::Status::SUCCESS
+        }
+        Some(address) => {
+            free_pages(address as u64, pages);
+            crate::base::Status::NOT_FOUND
+        }
+        None => crate::base::Status::OUT_OF_RESOURCES,
+    }
+}}
+
+eficall! {fn free_pages(
+    memory: crate::base::PhysicalAddress,
+    pages: usize,
+) -> crate::base::Status {
+    if !active() {
+        return crate::base::Status::NOT_READY;
+    }
+
+    match untrack(memory as usize, |k| matches!(k, Kind::Pages(n) if *n == pages)) {
+        Some(a) => {
+            unsafe { std::alloc::dealloc(memory as usize as *mut u8, a.layout) };
+            crate::base::Status::SUCCESS
+        }
+        None => crate::base::Status::NOT_FOUND,
+    }
+}}
+
+/// Allocation Tracker
+///
+/// The allocation tracker records all allocations performed through the memory-allocation
+/// services of a boot-services table, as returned by `boot_services_with_allocator()`. The
+/// allocations are served by the host allocator and tracked per thread, so only one tracker can be
+/// active on a thread at a time.
+///
+/// When the tracker is dropped, all outstanding allocations are released and reported as leaks by
+/// panicking (unless the thread is already panicking). Use `leaks()` to inspect them earlier.
+#[derive(Debug)]
+pub struct AllocationTracker {
+    // The tracker relies on thread-local state, so it must not be moved to another thread.
+    _thread: core::marker::PhantomData<*const ()>,
+}
+
+impl AllocationTracker {
+    /// Create a new tracker and activate it on the current thread.
+    ///
+    /// This panics if another tracker is already active on the current thread.
+    pub fn new() -> AllocationTracker {
+        ALLOCATIONS.with(|v| {
+            let mut v = v.borrow_mut();
+            assert!(v.is_none(), "AllocationTracker is already active");
+            *v = Some(std::collections::BTreeMap::new());
+        });
+
+        AllocationTracker {
+            _thread: core::marker::PhantomData,
+        }
+    }
+
+    /// Return the memory type and size in bytes of each outstanding allocation, ordered by
+    /// address.
+    pub fn leaks(&self) -> std::vec::Vec<(crate::system::MemoryType, usize)> {
+        ALLOCATIONS.with(|v| {
+            v.borrow()
+                .as_ref()
+                .unwrap()
+                .values()
+                .map(|a| match a.kind {
+                    Kind::Pool => (a.memory_type, a.layout.size()),
+                    Kind::Pages(n) => (a.memory_type, n * crate::system::PAGE_SIZE),
+                })
+                .collect()
+        })
+    }
+}
+
+impl Default for AllocationTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for AllocationTracker {
+    fn drop(&mut self) {
+        let leaks = self.leaks();
+        let allocations = ALLOCATIONS.with(|v| v.borrow_mut().take().unwrap());
+
+        for (address, a) in allocations {
+            unsafe { std::alloc::dealloc(address as *mut u8, a.layout) };
+        }
+
+        if !leaks.is_empty() && !std::thread::panicking() {
+            panic!("leaked {} allocation(s): {:?}", leaks.len(), leaks);
+        }
+    }
+}
+
+/// Create Mocked Boot Services with Allocator
+///
+/// This is like `boot_services()`, but implements `allocate_pool()`, `free_pool()`,
+/// `allocate_pages()`, and `free_pages()` in terms of the host allocator. All allocations are
+/// recorded by the `AllocationTracker` active on the calling thread. Invoking any of these
+/// services without an active tracker fails with `NOT_READY`.
+///
+/// Page allocations at fixed addresses are not supported and fail with `UNSUPPORTED`.
+pub fn boot_services_with_allocator() -> crate::system::BootServices {
+    let mut bs = boot_services();
+
+    bs.allocate_pool = allocate_pool;
+    bs.free_pool = free_pool;
+    bs.allocate_pages = allocate_pages;
+    bs.free_pages = free_pages;
+    bs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocation_tracker() {
+        let tracker = AllocationTracker::new();
+        let bs = boot_services_with_allocator();
+        let mut pool: *mut core::ffi::c_void = core::ptr::null_mut();
+        let mut pages: crate::base::PhysicalAddress = 0;
+
+        let r = (bs.allocate_pool)(crate::system::MemoryType::LoaderData, 13, &mut pool);
+        assert_eq!(r, crate::base::Status::SUCCESS);
+        assert_eq!(pool as usize % POOL_ALIGNMENT, 0);
+
+        let r = (bs.allocate_pages)(
+            crate::system::AllocateType::AllocateAnyPages,
+            crate::system::MemoryType::BootServicesData,
+            2,
+            &mut pages,
+        );
+        assert_eq!(r, crate::base::Status::SUCCESS);
+        assert_eq!(pages as usize % crate::system::PAGE_SIZE, 0);
+        assert_eq!(tracker.leaks().len(), 2);
+
+        // Mismatched releases are rejected and leave the allocations in place.
+        assert_eq!(
+            (bs.free_pool)(pages as usize as *mut core::ffi::c_void),
+            crate::base::Status::INVALID_PARAMETER,
+        );
+        assert_eq!((bs.free_pages)(pages, 1), crate::base::Status::NOT_FOUND);
+        assert_eq!(tracker.leaks().len(), 2);
+
+        assert_eq!((bs.free_pages)(pages, 2), crate::base::Status::SUCCESS);
+        assert!(matches!(
+            tracker.leaks()[..],
+            [(crate::system::MemoryType::LoaderData, 13)],
+        ));
+
+        assert_eq!((bs.free_pool)(pool), crate::base::Status::SUCCESS);
+        assert_eq!((bs.free_pool)(pool), crate::base::Status::INVALID_PARAMETER);
+        assert!(tracker.leaks().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "leaked 1 allocation(s)")]
+    fn allocation_tracker_leak() {
+        let _tracker = AllocationTracker::new();
+        let bs = boot_services_with_allocator();
+        let mut pool: *mut core::ffi::c_void = core::ptr::null_mut();
+
+        (bs.allocate_pool)(crate::system::MemoryType::LoaderData, 1, &mut pool);
+    }
+
+    #[test]
+    fn allocation_without_tracker() {
+        let bs = boot_services_with_allocator();
+        let mut pool: *mut core::ffi::c_void = core::ptr::null_mut();
+        let mut pages: crate::base::PhysicalAddress = 0;
+
+        assert_eq!(
+            (bs.allocate_pool)(crate::system::MemoryType::LoaderData, 1, &mut pool),
+            crate::base::Status::NOT_READY,
+        );
+        assert_eq!(
+            (bs.allocate_pages)(
+                crate::system::AllocateType::AllocateAnyPages,
+                crate::system::MemoryType::LoaderData,
+                1,
+                &mut pages,
+            ),
+            crate::base::Status::NOT_READY,
+        );
+        assert_eq!((bs.free_pool)(pool), crate::base::Status::NOT_READY);
+        assert_eq!((bs.free_pages)(pages, 1), crate::base::Status::NOT_READY);
+        assert!(pool.is_null());
+        assert_eq!(pages, 0);
+    }
+}
@@ -0,0 +1,557 @@
+//! Networking Helpers
+//!
+//! This module provides general helpers for use with the networking protocols. None of them are
+//! part of the specification. This includes endpoint types pairing an address with a port, the
+//! internet checksum, wrappers to join and leave multicast groups, a poll loop to wait for
+//! completion tokens, fragment-table copies, and an iterator over DHCPv4 options.
+
+/// IPv4 Endpoint
+///
+/// The `Ipv4Endpoint` type pairs an IPv4 address with a port number. The port is stored in host
+/// byte order, as is the case for all port fields of the networking protocols.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ipv4Endpoint {
+    pub address: crate::base::Ipv4Address,
+    pub port: u16,
+}
+
+/// IPv6 Endpoint
+///
+/// The `Ipv6Endpoint` type pairs an IPv6 address with a port number. The port is stored in host
+/// byte order, as is the case for all port fields of the networking protocols.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ipv6Endpoint {
+    pub address: crate::base::Ipv6Address,
+    pub port: u16,
+}
+
+impl Ipv4Endpoint {
+    pub const fn new(address: crate::base::Ipv4Address, port: u16) -> Ipv4Endpoint {
+        Ipv4Endpoint { address, port }
+    }
+}
+
+impl Ipv6Endpoint {
+    pub const fn new(address: crate::base::Ipv6Address, port: u16) -> Ipv6Endpoint {
+        Ipv6Endpoint { address, port }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::Ipv4Addr> for crate::base::Ipv4Address {
+    fn from(v: std::net::Ipv4Addr) -> Self {
+        crate::base::Ipv4Address { addr: v.octets() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<crate::base::Ipv4Address> for std::net::Ipv4Addr {
+    fn from(v: crate::base::Ipv4Address) -> Self {
+        std::net::Ipv4Addr::from(v.addr)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::Ipv6Addr> for crate::base::Ipv6Address {
+    fn from(v: std::net::Ipv6Addr) -> Self {
+        crate::base::Ipv6Address { addr: v.octets() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<crate::base::Ipv6Address> for std::net::Ipv6Addr {
+    fn from(v: crate::base::Ipv6Address) -> Self {
+        std::net::Ipv6Addr::from(v.addr)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::net::SocketAddrV4> for Ipv4Endpoint {
+    fn from(v: std::net::SocketAddrV4) -> Self {
+        Ipv4Endpoint::new((*v.ip()).into(), v.port())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Ipv4Endpoint> for std::net::SocketAddrV4 {
+    fn from(v: Ipv4Endpoint) -> Self {
+        std::net::SocketAddrV4::new(v.address.into(), v.port)
+    }
+}
+
+/// Note that the flow information and scope ID of the socket address are dropped, since the
+/// endpoint has no place to store them.
+#[cfg(feature = "std")]
+impl From<std::net::SocketAddrV6> for Ipv6Endpoint {
+    fn from(v: std::net::SocketAddrV6) -> Self {
+        Ipv6Endpoint::new((*v.ip()).into(), v.port())
+    }
+}
+
+/// Note that the flow information and scope ID of the resulting socket address are zero.
+#[cfg(feature = "std")]
+impl From<Ipv6Endpoint> for std::net::SocketAddrV6 {
+    fn from(v: Ipv6Endpoint) -> Self {
+        std::net::SocketAddrV6::new(v.address.into(), v.port, 0, 0)
+    }
+}
+
+/// Internet Checksum
+///
+/// This computes the 16-bit one's-complement checksum of RFC 1071, as used by IPv4, ICMP, UDP,
+/// and TCP. The data is summed as big-endian 16-bit words, with odd-length data padded by a zero
+/// byte. Carries are folded back into the sum, and the complement of the sum is returned.
+///
+/// The result is in host byte order. Store it with `to_be_bytes()`. Checksumming data that
+/// includes a valid checksum yields 0.
+pub fn internet_checksum(data: &[u8]) -> u16 {
+    let mut sum: u64 = 0;
+    let mut words = data.chunks_exact(2);
+
+    for w in &mut words {
+        sum += u16::from_be_bytes([w[0], w[1]]) as u64;
+    }
+    if let [v] = words.remainder() {
+        sum += u16::from_be_bytes([*v, 0]) as u64;
+    }
+
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+
+    !(sum as u16)
+}
+
+/// IPv4 Multicast Group Function
+///
+/// This is the signature of the `groups()` member of the IPv4-based protocols (e.g., IP4 and
+/// UDP4), with `P` being the respective protocol structure. It joins (or leaves) the given
+/// multicast group. If no group is given when leaving, all groups are left.
+pub type Ipv4Groups<P> = eficall! {fn(
+    *mut P,
+    crate::base::Boolean,
+    *mut crate::base::Ipv4Address,
+) -> crate::base::Status};
+
+/// IPv6 Multicast Group Function
+///
+/// This is the IPv6 counterpart of `Ipv4Groups`, as used by the IP6 and UDP6 protocols.
+pub type Ipv6Groups<P> = eficall! {fn(
+    *mut P,
+    crate::base::Boolean,
+    *mut crate::base::Ipv6Address,
+) -> crate::base::Status};
+
+fn result(r: crate::base::Status) -> Result<(), crate::base::Status> {
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(())
+    }
+}
+
+/// Join IPv4 Multicast Group
+///
+/// This invokes `groups`, the `groups()` member of the protocol instance `proto`, to join the
+/// multicast group `group`.
+pub fn join_multicast<P>(
+    proto: *mut P,
+    groups: Ipv4Groups<P>,
+    group: crate::base::Ipv4Address,
+) -> Result<(), crate::base::Status> {
+    let mut group = group;
+
+    result(groups(proto, crate::base::Boolean::TRUE, &mut group))
+}
+
+/// Leave All IPv4 Multicast Groups
+///
+/// This invokes `groups`, the `groups()` member of the protocol instance `proto`, to leave all
+/// multicast groups.
+pub fn leave_all_multicast<P>(
+    proto: *mut P,
+    groups: Ipv4Groups<P>,
+) -> Result<(), crate::base::Status> {
+    result(groups(
+        proto,
+        crate::base::Boolean::FALSE,
+        core::ptr::null_mut(),
+    ))
+}
+
+/// Join IPv6 Multicast Group
+///
+/// This is the IPv6 counterpart of `join_multicast()`.
+pub fn join_multicast6<P>(
+    proto: *mut P,
+    groups: Ipv6Groups<P>,
+    group: crate::base::Ipv6Address,
+) -> Result<(), crate::base::Status> {
+    let mut group = group;
+
+    result(groups(proto, crate::base::Boolean::TRUE, &mut group))
+}
+
+/// Leave All IPv6 Multicast Groups
+///
+/// This is the IPv6 counterpart of `leave_all_multicast()`.
+pub fn leave_all_multicast6<P>(
+    proto: *mut P,
+    groups: Ipv6Groups<P>,
+) -> Result<(), crate::base::Status> {
+    result(groups(
+        proto,
+        crate::base::Boolean::FALSE,
+        core::ptr::null_mut(),
+    ))
+}
+
+/// Wait for Completion Token
+///
+/// The networking protocols signal completion of asynchronous requests via the event of the
+/// completion token. Unless the driver is polled, the request might never complete. This calls
+/// `poll` (usually the `poll()` member of the protocol) and then `check_event()` of the boot
+/// services in a loop, until `event` is signaled. The caller must then check the status of the
+/// completion token.
+///
+/// `NOT_READY` and `TIMEOUT` returned by `poll` are ignored, since they merely tell that no data
+/// was processed. Any other error of `poll` or `check_event()` is returned.
+pub fn wait_token(
+    bs: &crate::system::BootServices,
+    event: crate::base::Event,
+    mut poll: impl FnMut() -> crate::base::Status,
+) -> Result<(), crate::base::Status> {
+    loop {
+        let r = poll();
+        if r.is_error() && r != crate::base::Status::NOT_READY && r != crate::base::Status::TIMEOUT
+        {
+            return Err(r);
+        }
+
+        let r = (bs.check_event)(event);
+        if r == crate::base::Status::NOT_READY {
+            continue;
+        }
+
+        return result(r);
+    }
+}
+
+/// Copy Fragment Table
+///
+/// The networking protocols pass packet data as a table of fragments. All of them (e.g., MNP,
+/// IP4, UDP4, and TCP4) share the fragment layout of the managed-network protocol. This
+/// concatenates the data of all `fragments` into `out` and returns the total number of bytes.
+/// If `out` cannot hold all data, `BUFFER_TOO_SMALL` is returned and `out` is left untouched.
+///
+/// # Safety
+///
+/// Each fragment must point to `fragment_length` readable bytes, unless its length is 0.
+pub unsafe fn copy_fragments(
+    fragments: &[crate::protocols::managed_network::FragmentData],
+    out: &mut [u8],
+) -> Result<usize, crate::base::Status> {
+    let total = fragments
+        .iter()
+        .try_fold(0usize, |acc, f| acc.checked_add(f.fragment_length as usize));
+    match total {
+        Some(total) if total <= out.len() => {}
+        _ => return Err(crate::base::Status::BUFFER_TOO_SMALL),
+    }
+
+    let mut offset = 0;
+    for f in fragments {
+        let len = f.fragment_length as usize;
+        if len > 0 {
+            out[offset..offset + len].copy_from_slice(core::slice::from_raw_parts(
+                f.fragment_buffer as *const u8,
+                len,
+            ));
+            offset += len;
+        }
+    }
+
+    Ok(offset)
+}
+
+pub const DHCP4_OPTION_PAD: u8 = 0x00u8;
+pub const DHCP4_OPTION_END: u8 = 0xffu8;
+
+/// DHCP4 Option Iterator
+///
+/// This walks the options area of a DHCP4 packet (i.e., the data following the magic cookie),
+/// which is a sequence of tag-length-value encoded options. Each option is yielded as a pair of
+/// its tag and its data. Pad options are skipped, and iteration stops at the end option.
+///
+/// Iteration also stops at the end of the options area, or if an option exceeds it. Option data
+/// is never read beyond the end of the given slice.
+#[derive(Clone, Debug)]
+pub struct Dhcp4OptionIter<'a> {
+    options: &'a [u8],
+}
+
+impl<'a> Dhcp4OptionIter<'a> {
+    pub fn new(options: &'a [u8]) -> Dhcp4OptionIter<'a> {
+        Dhcp4OptionIter { options }
+    }
+}
+
+impl<'a> Iterator for Dhcp4OptionIter<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.options {
+                [DHCP4_OPTION_PAD, rest @ ..] => self.options = rest,
+                [tag, len, rest @ ..]
+                    if *tag != DHCP4_OPTION_END && *len as usize <= rest.len() =>
+                {
+                    let (data, rest) = rest.split_at(*len as usize);
+                    self.options = rest;
+                    return Some((*tag, data));
+                }
+                _ => {
+                    self.options = &[];
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum() {
+        // RFC 1071, section 3: the sum of these words is 0xddf2.
+        assert_eq!(
+            internet_checksum(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]),
+            !0xddf2,
+        );
+
+        // Sample IPv4 header with the checksum field cleared, then set.
+        let mut header = [
+            0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8,
+            0x00, 0x01, 0xc0, 0xa8, 0x00, 0xc7,
+        ];
+        let sum = internet_checksum(&header);
+        assert_eq!(sum, 0xb861);
+        header[10..12].copy_from_slice(&sum.to_be_bytes());
+        assert_eq!(internet_checksum(&header), 0);
+
+        // Odd lengths are padded with zero, and empty data sums to 0.
+        assert_eq!(internet_checksum(&[0x12, 0x34, 0x56]), !0x6834);
+        assert_eq!(internet_checksum(&[]), 0xffff);
+
+        // Carries wrap around.
+        assert_eq!(internet_checksum(&[0xff, 0xff, 0x00, 0x01]), !0x0001);
+    }
+
+    #[test]
+    fn fragments() {
+        use crate::protocols::managed_network::FragmentData;
+
+        let mut a = *b"Hello";
+        let mut b = *b", ";
+        let mut c = *b"World!";
+        let table = [
+            FragmentData {
+                fragment_length: 5,
+                fragment_buffer: a.as_mut_ptr() as *mut core::ffi::c_void,
+            },
+            FragmentData {
+                fragment_length: 0,
+                fragment_buffer: core::ptr::null_mut(),
+            },
+            FragmentData {
+                fragment_length: 2,
+                fragment_buffer: b.as_mut_ptr() as *mut core::ffi::c_void,
+            },
+            FragmentData {
+                fragment_length: 6,
+                fragment_buffer: c.as_mut_ptr() as *mut core::ffi::c_void,
+            },
+        ];
+
+        let mut out = [0u8; 16];
+        assert_eq!(unsafe { copy_fragments(&table, &mut out) }, Ok(13));
+        assert_eq!(&out[..13], b"Hello, World!");
+        assert_eq!(unsafe { copy_fragments(&[], &mut out) }, Ok(0));
+
+        // Short buffers are rejected without partial copies.
+        let mut out = [0u8; 12];
+        assert_eq!(
+            unsafe { copy_fragments(&table, &mut out) },
+            Err(crate::base::Status::BUFFER_TOO_SMALL),
+        );
+        assert_eq!(out, [0u8; 12]);
+    }
+
+    #[test]
+    fn dhcp4_options() {
+        let options = [
+            0x35, 0x01, 0x05, // message type: ack
+            0x00, 0x00, // pad
+            0x03, 0x04, 0xc0, 0xa8, 0x00, 0x01, // router
+            0x06, 0x00, // empty option
+            0x00, // pad
+            0xff, // end
+            0x01, 0x04, 0xff, 0xff, 0xff, 0x00, // trailing garbage
+        ];
+        let v: Vec<(u8, &[u8])> = Dhcp4OptionIter::new(&options).collect();
+        assert_eq!(
+            v,
+            [
+                (0x35, &[0x05][..]),
+                (0x03, &[0xc0, 0xa8, 0x00, 0x01][..]),
+                (0x06, &[][..]),
+            ],
+        );
+
+        // Options exceeding the buffer terminate the iteration.
+        let v: Vec<(u8, &[u8])> =
+            Dhcp4OptionIter::new(&[0x35, 0x01, 0x05, 0x03, 0x04, 0xc0]).collect();
+        assert_eq!(v, [(0x35, &[0x05][..])]);
+        assert_eq!(Dhcp4OptionIter::new(&[0x35]).next(), None);
+        assert_eq!(Dhcp4OptionIter::new(&[0x00, 0x00]).next(), None);
+        assert_eq!(Dhcp4OptionIter::new(&[]).next(), None);
+
+        // The iterator is fused once it stopped.
+        let mut iter = Dhcp4OptionIter::new(&[0x03, 0x08, 0x00, 0x01, 0x01, 0x00]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    std::thread_local! {
+        static PENDING: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        static EVENTS: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn check_event(event: crate::base::Event) -> crate::base::Status {
+        EVENTS.with(|v| v.borrow_mut().push(event as usize));
+
+        match PENDING.with(|v| v.get()) {
+            0 => crate::base::Status::SUCCESS,
+            usize::MAX => crate::base::Status::INVALID_PARAMETER,
+            n => {
+                PENDING.with(|v| v.set(n - 1));
+                crate::base::Status::NOT_READY
+            }
+        }
+    }}
+
+    #[test]
+    fn wait_tokens() {
+        let mut bs = crate::mock::boot_services();
+        bs.check_event = check_event;
+        let event = 0x10 as crate::base::Event;
+        let mut polls = 0;
+
+        // The event is signaled after 3 polls. Idle polls are ignored.
+        PENDING.with(|v| v.set(2));
+        let r = wait_token(&bs, event, || {
+            polls += 1;
+            crate::base::Status::NOT_READY
+        });
+        assert_eq!(r, Ok(()));
+        assert_eq!(polls, 3);
+
+        // Errors of either poll or check_event() are propagated.
+        PENDING.with(|v| v.set(5));
+        let r = wait_token(&bs, event, || crate::base::Status::DEVICE_ERROR);
+        assert_eq!(r, Err(crate::base::Status::DEVICE_ERROR));
+
+        PENDING.with(|v| v.set(usize::MAX));
+        let r = wait_token(&bs, event, || crate::base::Status::SUCCESS);
+        assert_eq!(r, Err(crate::base::Status::INVALID_PARAMETER));
+
+        let events = EVENTS.with(|v| v.take());
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().all(|v| *v == 0x10));
+    }
+
+    struct Proto {
+        joined: Vec<[u8; 16]>,
+    }
+
+    eficall! {fn groups4(
+        this: *mut Proto,
+        join: crate::base::Boolean,
+        group: *mut crate::base::Ipv4Address,
+    ) -> crate::base::Status {
+        let this = unsafe { &mut *this };
+
+        match (join.into(), group.is_null()) {
+            (true, false) => {
+                let mut v = [0u8; 16];
+                v[..4].copy_from_slice(&unsafe { *group }.addr);
+                this.joined.push(v);
+            }
+            (false, true) => this.joined.clear(),
+            _ => return crate::base::Status::INVALID_PARAMETER,
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn groups6(
+        this: *mut Proto,
+        join: crate::base::Boolean,
+        group: *mut crate::base::Ipv6Address,
+    ) -> crate::base::Status {
+        let this = unsafe { &mut *this };
+
+        match (join.into(), group.is_null()) {
+            (true, false) => this.joined.push(unsafe { *group }.addr),
+            (false, true) => this.joined.clear(),
+            _ => return crate::base::Status::INVALID_PARAMETER,
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn multicast_groups() {
+        let mut proto = Proto { joined: Vec::new() };
+        let v4 = crate::base::Ipv4Address {
+            addr: [224, 0, 0, 251],
+        };
+        let mut v6 = crate::base::Ipv6Address::default();
+        v6.addr[0] = 0xff;
+        v6.addr[1] = 0x02;
+        v6.addr[15] = 0xfb;
+
+        assert_eq!(join_multicast(&mut proto, groups4, v4), Ok(()));
+        assert_eq!(proto.joined.len(), 1);
+        assert_eq!(proto.joined[0][..4], v4.addr);
+        assert_eq!(leave_all_multicast(&mut proto, groups4), Ok(()));
+        assert!(proto.joined.is_empty());
+
+        assert_eq!(join_multicast6(&mut proto, groups6, v6), Ok(()));
+        assert_eq!(proto.joined, [v6.addr]);
+        assert_eq!(leave_all_multicast6(&mut proto, groups6), Ok(()));
+        assert!(proto.joined.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn socket_conversion() {
+        let s4: std::net::SocketAddrV4 = "192.168.0.1:8080".parse().unwrap();
+        let e4 = Ipv4Endpoint::from(s4);
+
+        assert_eq!(e4.address.addr, [192, 168, 0, 1]);
+        assert_eq!(e4.port, 8080);
+        assert_eq!(std::net::SocketAddrV4::from(e4), s4);
+
+        let s6: std::net::SocketAddrV6 = "[fe80::1]:443".parse().unwrap();
+        let e6 = Ipv6Endpoint::from(s6);
+
+        assert_eq!(e6.address.addr[0], 0xfe);
+        assert_eq!(e6.address.addr[1], 0x80);
+        assert_eq!(e6.address.addr[15], 0x01);
+        assert_eq!(e6.port, 443);
+        assert_eq!(std::net::SocketAddrV6::from(e6), s6);
+    }
+}
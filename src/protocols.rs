@@ -4,14 +4,60 @@
 //! refer to each other, but their documentation and implementation is split apart. We provide
 //! each protocol as a separate module, so it is clearly defined where a symbol belongs to.
 
+pub mod absolute_pointer;
+pub mod block_io;
+#[cfg(feature = "edk2")]
+pub mod boot_logo;
+#[cfg(feature = "legacy")]
+pub mod component_name;
+pub mod component_name2;
+#[cfg(feature = "pi")]
+pub mod cpu_arch;
 pub mod decompress;
 pub mod device_path;
+pub mod device_path_from_text;
+pub mod device_path_to_text;
 pub mod device_path_utilities;
+pub mod driver_binding;
 pub mod file;
 pub mod graphics_output;
+pub mod hash;
+pub mod hash2;
+pub mod ip6_config;
+pub mod load_file;
+pub mod load_file2;
 pub mod loaded_image;
 pub mod loaded_image_device_path;
+pub mod managed_network;
+#[cfg(feature = "pi")]
+pub mod metronome;
+pub mod pxe_base_code;
+pub mod rng;
+pub mod serial_io;
+pub mod shell;
+#[cfg(feature = "legacy")]
+pub mod shell_interface;
+pub mod shell_parameters;
 pub mod simple_file_system;
+pub mod simple_network;
+pub mod simple_pointer;
 pub mod simple_text_input;
 pub mod simple_text_input_ex;
 pub mod simple_text_output;
+pub mod tcg2;
+pub mod unicode_collation;
+pub mod usb2_host_controller;
+#[cfg(feature = "edk2")]
+pub mod variable_policy;
+#[cfg(feature = "pi")]
+pub mod watchdog_timer;
+
+/// Protocol GUID
+///
+/// This trait ties a protocol structure to the GUID that identifies the protocol. It is
+/// implemented by the protocol structures of all modules in this namespace that have a GUID
+/// assigned. It allows generic code to look up protocols by type, rather than passing the GUID
+/// alongside.
+pub trait ProtocolGuid {
+    const GUID: crate::base::Guid;
+}
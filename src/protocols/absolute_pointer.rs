@@ -0,0 +1,142 @@
+//! Absolute Pointer Protocol
+//!
+//! The absolute pointer protocol provides access to pointer devices that report absolute
+//! coordinates, like touch screens and digitizers. Unlike the simple pointer protocol, positions
+//! are reported within a device-specific range, rather than as relative movement.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8d59d32b,
+    0xc655,
+    0x4ae9,
+    0x9b,
+    0x15,
+    &[0xf2, 0x59, 0x04, 0x99, 0x2a, 0x43],
+);
+
+pub const SUPPORTS_ALT_ACTIVE: u32 = 0x00000001u32;
+pub const SUPPORTS_PRESSURE_AS_Z: u32 = 0x00000002u32;
+
+pub const TOUCH_ACTIVE: u32 = 0x00000001u32;
+pub const ALT_ACTIVE: u32 = 0x00000002u32;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Mode {
+    pub absolute_min_x: u64,
+    pub absolute_min_y: u64,
+    pub absolute_min_z: u64,
+    pub absolute_max_x: u64,
+    pub absolute_max_y: u64,
+    pub absolute_max_z: u64,
+    pub attributes: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct State {
+    pub current_x: u64,
+    pub current_y: u64,
+    pub current_z: u64,
+    pub active_buttons: u32,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub reset: eficall! {fn(
+        *mut Protocol,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub get_state: eficall! {fn(
+        *mut Protocol,
+        *mut State,
+    ) -> crate::base::Status},
+    pub wait_for_input: crate::base::Event,
+    pub mode: *mut Mode,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+// Scale `current` from the range `[min, max]` to `[0, size - 1]`. Values outside of the range are
+// clamped. An empty or inverted range maps everything to 0.
+fn scale(current: u64, min: u64, max: u64, size: u32) -> u32 {
+    if max <= min || size == 0 {
+        return 0;
+    }
+
+    let offset = (current.clamp(min, max) - min) as u128;
+    (offset * (size - 1) as u128 / (max - min) as u128) as u32
+}
+
+/// Map Pointer Position to Screen
+///
+/// This linearly scales the current X and Y coordinates of `state` from the range reported by
+/// `mode` to a screen of `screen_w` times `screen_h` pixels. The minimum of each axis maps to
+/// pixel 0, the maximum to the last pixel. Coordinates outside of the reported range are clamped.
+/// If an axis has an empty range (i.e., its maximum does not exceed its minimum), that axis maps
+/// to 0.
+pub fn map_to_screen(mode: &Mode, state: &State, screen_w: u32, screen_h: u32) -> (u32, u32) {
+    (
+        scale(
+            state.current_x,
+            mode.absolute_min_x,
+            mode.absolute_max_x,
+            screen_w,
+        ),
+        scale(
+            state.current_y,
+            mode.absolute_min_y,
+            mode.absolute_max_y,
+            screen_h,
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(x: u64, y: u64) -> State {
+        State {
+            current_x: x,
+            current_y: y,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn screen_mapping() {
+        let mode = Mode {
+            absolute_min_x: 100,
+            absolute_max_x: 1100,
+            absolute_min_y: 0,
+            absolute_max_y: u64::MAX,
+            ..Default::default()
+        };
+
+        assert_eq!(map_to_screen(&mode, &at(100, 0), 1024, 768), (0, 0));
+        assert_eq!(
+            map_to_screen(&mode, &at(1100, u64::MAX), 1024, 768),
+            (1023, 767)
+        );
+        assert_eq!(
+            map_to_screen(&mode, &at(600, u64::MAX / 2), 1025, 769),
+            (512, 383)
+        );
+
+        // Out-of-range coordinates are clamped.
+        assert_eq!(map_to_screen(&mode, &at(0, 0), 1024, 768), (0, 0));
+        assert_eq!(map_to_screen(&mode, &at(5000, 0), 1024, 768), (1023, 0));
+
+        // Degenerate axes and screens map to 0.
+        let flat = Mode {
+            absolute_min_x: 10,
+            absolute_max_x: 10,
+            absolute_max_y: 100,
+            ..Default::default()
+        };
+        assert_eq!(map_to_screen(&flat, &at(10, 100), 1024, 768), (0, 767));
+        assert_eq!(map_to_screen(&mode, &at(1100, 0), 0, 0), (0, 0));
+    }
+}
@@ -0,0 +1,70 @@
+//! Block I/O Protocol
+//!
+//! The block I/O protocol provides access to block devices, like disks and partitions. Blocks are
+//! addressed by their logical block address, and transfers must be multiples of the block size of
+//! the media.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x964e5b21,
+    0x6459,
+    0x11d2,
+    0x8e,
+    0x39,
+    &[0x00, 0xa0, 0xc9, 0x69, 0x72, 0x3b],
+);
+
+pub const REVISION: u64 = 0x0000000000010000u64;
+pub const REVISION2: u64 = 0x0000000000020001u64;
+pub const REVISION3: u64 = 0x000000000002001fu64;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Media {
+    pub media_id: u32,
+    pub removable_media: crate::base::Boolean,
+    pub media_present: crate::base::Boolean,
+    pub logical_partition: crate::base::Boolean,
+    pub read_only: crate::base::Boolean,
+    pub write_caching: crate::base::Boolean,
+    pub block_size: u32,
+    pub io_align: u32,
+    pub last_block: crate::base::Lba,
+
+    // Added in REVISION2.
+    pub lowest_aligned_lba: crate::base::Lba,
+    pub logical_blocks_per_physical_block: u32,
+
+    // Added in REVISION3.
+    pub optimal_transfer_length_granularity: u32,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub revision: u64,
+    pub media: *mut Media,
+    pub reset: eficall! {fn(
+        *mut Protocol,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub read_blocks: eficall! {fn(
+        *mut Protocol,
+        u32,
+        crate::base::Lba,
+        usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub write_blocks: eficall! {fn(
+        *mut Protocol,
+        u32,
+        crate::base::Lba,
+        usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub flush_blocks: eficall! {fn(
+        *mut Protocol,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -0,0 +1,63 @@
+//! Boot Logo Protocol
+//!
+//! The boot-logo protocols allow platforms to record the boot logo rendered by the firmware, so
+//! later boot stages can preserve or restore it. The second revision allows retrieving the logo as
+//! well. Both are defined by EDK2 rather than the UEFI specification.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xcdea2bd3,
+    0xfc25,
+    0x4c1c,
+    0xb9,
+    0x7c,
+    &[0xb3, 0x11, 0x86, 0x06, 0x49, 0x90],
+);
+
+pub const PROTOCOL2_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x4b5dc1df,
+    0x1eaa,
+    0x48f2,
+    0xa7,
+    0xe9,
+    &[0xea, 0xc4, 0x89, 0xa0, 0x0b, 0x5c],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub set_boot_logo: eficall! {fn(
+        *mut Protocol,
+        *mut crate::protocols::graphics_output::BltPixel,
+        usize,
+        usize,
+        usize,
+        usize,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+#[repr(C)]
+pub struct Protocol2 {
+    pub set_boot_logo: eficall! {fn(
+        *mut Protocol2,
+        *mut crate::protocols::graphics_output::BltPixel,
+        usize,
+        usize,
+        usize,
+        usize,
+    ) -> crate::base::Status},
+    pub get_boot_logo: eficall! {fn(
+        *mut Protocol2,
+        *mut *mut crate::protocols::graphics_output::BltPixel,
+        *mut usize,
+        *mut usize,
+        *mut usize,
+        *mut usize,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol2 {
+    const GUID: crate::base::Guid = PROTOCOL2_GUID;
+}
@@ -0,0 +1,35 @@
+//! Component Name Protocol
+//!
+//! The component name protocol is the predecessor of the component name 2 protocol. It has the
+//! same layout, but languages are given as ISO-639-2 language codes (e.g., `eng`), and
+//! `supported_languages` is a plain concatenation of these 3-character codes.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x107a772c,
+    0xd5e1,
+    0x11d4,
+    0x9a,
+    0x46,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_driver_name: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char8,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_controller_name: eficall! {fn(
+        *mut Protocol,
+        crate::base::Handle,
+        crate::base::Handle,
+        *mut crate::base::Char8,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub supported_languages: *mut crate::base::Char8,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -0,0 +1,35 @@
+//! Component Name 2 Protocol
+//!
+//! The component name 2 protocol is installed by UEFI drivers to provide human-readable names of
+//! the driver and of the controllers it manages. Names are available in a set of languages, which
+//! are given as RFC-4646 language codes.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x6a7a5cff,
+    0xe8d9,
+    0x4f70,
+    0xba,
+    0xda,
+    &[0x75, 0xab, 0x30, 0x25, 0xce, 0x14],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_driver_name: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char8,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_controller_name: eficall! {fn(
+        *mut Protocol,
+        crate::base::Handle,
+        crate::base::Handle,
+        *mut crate::base::Char8,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub supported_languages: *mut crate::base::Char8,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -0,0 +1,95 @@
+//! CPU Architectural Protocol
+//!
+//! The CPU architectural protocol is defined by the PI specification. It abstracts the
+//! processor-specific functionality needed by the DXE core, including cache management, interrupt
+//! control, timers, and memory attributes.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x26baccb1,
+    0x6f42,
+    0x11d4,
+    0xbc,
+    0xe7,
+    &[0x00, 0x80, 0xc7, 0x3c, 0x88, 0x81],
+);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum CpuFlushType {
+    CpuFlushTypeWriteBackInvalidate,
+    CpuFlushTypeWriteBack,
+    CpuFlushTypeInvalidate,
+    CpuMaxFlushType,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum CpuInitType {
+    CpuInit,
+    CpuMaxInitType,
+}
+
+/// Exception Type
+///
+/// The exception (or interrupt vector) number is an architecture-specific, native-sized integer.
+pub type ExceptionType = isize;
+
+/// System Context
+///
+/// The system context is a union of pointers to the architecture-specific register context of
+/// the interrupted processor. Since all members are pointers, it is represented as a single
+/// untyped pointer here.
+pub type SystemContext = *mut core::ffi::c_void;
+
+pub type InterruptHandler = eficall! {fn(
+    ExceptionType,
+    SystemContext,
+)};
+
+#[repr(C)]
+pub struct Protocol {
+    pub flush_data_cache: eficall! {fn(
+        *mut Protocol,
+        crate::base::PhysicalAddress,
+        u64,
+        CpuFlushType,
+    ) -> crate::base::Status},
+    pub enable_interrupt: eficall! {fn(
+        *mut Protocol,
+    ) -> crate::base::Status},
+    pub disable_interrupt: eficall! {fn(
+        *mut Protocol,
+    ) -> crate::base::Status},
+    pub get_interrupt_state: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub init: eficall! {fn(
+        *mut Protocol,
+        CpuInitType,
+    ) -> crate::base::Status},
+    pub register_interrupt_handler: eficall! {fn(
+        *mut Protocol,
+        ExceptionType,
+        // XXX: This is optional. Pass `None` to unregister a handler.
+        Option<InterruptHandler>,
+    ) -> crate::base::Status},
+    pub get_timer_value: eficall! {fn(
+        *mut Protocol,
+        u32,
+        *mut u64,
+        *mut u64,
+    ) -> crate::base::Status},
+    pub set_memory_attributes: eficall! {fn(
+        *mut Protocol,
+        crate::base::PhysicalAddress,
+        u64,
+        u64,
+    ) -> crate::base::Status},
+    pub number_of_timers: u32,
+    pub dma_buffer_alignment: u32,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
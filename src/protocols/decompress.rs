@@ -31,3 +31,7 @@ pub struct Protocol {
         u32,
     ) -> crate::base::Status},
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -27,6 +27,10 @@ pub struct Protocol {
     pub length: [u8; 2],
 }
 
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct End {
@@ -52,3 +56,538 @@ impl Hardware {
     pub const SUBTYPE_CONTROLLER: u8 = 0x05;
     pub const SUBTYPE_BMC: u8 = 0x06;
 }
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Acpi {
+    pub header: Protocol,
+}
+
+impl Acpi {
+    pub const SUBTYPE_ACPI: u8 = 0x01;
+    pub const SUBTYPE_EXPANDED_ACPI: u8 = 0x02;
+    pub const SUBTYPE_ADR: u8 = 0x03;
+    pub const SUBTYPE_NVDIMM: u8 = 0x04;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Messaging {
+    pub header: Protocol,
+}
+
+impl Messaging {
+    pub const SUBTYPE_ATAPI: u8 = 0x01;
+    pub const SUBTYPE_SCSI: u8 = 0x02;
+    pub const SUBTYPE_FIBRE_CHANNEL: u8 = 0x03;
+    pub const SUBTYPE_1394: u8 = 0x04;
+    pub const SUBTYPE_USB: u8 = 0x05;
+    pub const SUBTYPE_I2O: u8 = 0x06;
+    pub const SUBTYPE_INFINIBAND: u8 = 0x09;
+    pub const SUBTYPE_VENDOR: u8 = 0x0a;
+    pub const SUBTYPE_MAC: u8 = 0x0b;
+    pub const SUBTYPE_IPV4: u8 = 0x0c;
+    pub const SUBTYPE_IPV6: u8 = 0x0d;
+    pub const SUBTYPE_UART: u8 = 0x0e;
+    pub const SUBTYPE_USB_CLASS: u8 = 0x0f;
+    pub const SUBTYPE_USB_WWID: u8 = 0x10;
+    pub const SUBTYPE_DEVICE_LOGICAL_UNIT: u8 = 0x11;
+    pub const SUBTYPE_SATA: u8 = 0x12;
+    pub const SUBTYPE_ISCSI: u8 = 0x13;
+    pub const SUBTYPE_VLAN: u8 = 0x14;
+    pub const SUBTYPE_FIBRE_CHANNEL_EX: u8 = 0x15;
+    pub const SUBTYPE_SAS_EX: u8 = 0x16;
+    pub const SUBTYPE_NVME_NAMESPACE: u8 = 0x17;
+    pub const SUBTYPE_URI: u8 = 0x18;
+    pub const SUBTYPE_UFS: u8 = 0x19;
+    pub const SUBTYPE_SD: u8 = 0x1a;
+    pub const SUBTYPE_BLUETOOTH: u8 = 0x1b;
+    pub const SUBTYPE_WIFI: u8 = 0x1c;
+    pub const SUBTYPE_EMMC: u8 = 0x1d;
+    pub const SUBTYPE_BLUETOOTH_LE: u8 = 0x1e;
+    pub const SUBTYPE_DNS: u8 = 0x1f;
+    pub const SUBTYPE_NVDIMM_NAMESPACE: u8 = 0x20;
+    pub const SUBTYPE_REST_SERVICE: u8 = 0x21;
+    pub const SUBTYPE_NVME_OF: u8 = 0x22;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Media {
+    pub header: Protocol,
+}
+
+impl Media {
+    pub const SUBTYPE_HARDDRIVE: u8 = 0x01;
+    pub const SUBTYPE_CDROM: u8 = 0x02;
+    pub const SUBTYPE_VENDOR: u8 = 0x03;
+    pub const SUBTYPE_FILE_PATH: u8 = 0x04;
+    pub const SUBTYPE_MEDIA_PROTOCOL: u8 = 0x05;
+    pub const SUBTYPE_PIWG_FIRMWARE_FILE: u8 = 0x06;
+    pub const SUBTYPE_PIWG_FIRMWARE_VOLUME: u8 = 0x07;
+    pub const SUBTYPE_RELATIVE_OFFSET_RANGE: u8 = 0x08;
+    pub const SUBTYPE_RAM_DISK: u8 = 0x09;
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Bios {
+    pub header: Protocol,
+}
+
+impl Bios {
+    pub const SUBTYPE_BOOT_SPECIFICATION: u8 = 0x01;
+}
+
+/// Device-Path Node Iterator
+///
+/// This walks the nodes of a device path and yields each node header. The length of each node is
+/// read from its little-endian length field, and the next node starts right after it. Iteration
+/// stops at the end-of-entire-path node, which is not yielded. End-of-instance nodes of
+/// multi-instance paths are yielded like any other node.
+///
+/// If a node claims a length shorter than its header, the path is malformed and iteration stops
+/// as well.
+#[derive(Clone, Debug)]
+pub struct DevicePathNodes<'a> {
+    next: *const u8,
+    _lifetime: core::marker::PhantomData<&'a Protocol>,
+}
+
+impl<'a> DevicePathNodes<'a> {
+    /// Create an iterator over the nodes of the device path at `start`.
+    ///
+    /// # Safety
+    ///
+    /// `start` must either be null or point to a device path that is readable up to and including
+    /// its end-of-entire-path node, and stays valid and unmodified for the lifetime `'a`. A null
+    /// pointer yields no nodes.
+    pub unsafe fn new(start: *const Protocol) -> DevicePathNodes<'a> {
+        DevicePathNodes {
+            next: start as *const u8,
+            _lifetime: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for DevicePathNodes<'a> {
+    type Item = &'a Protocol;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_null() {
+            return None;
+        }
+
+        let node = unsafe { &*(self.next as *const Protocol) };
+        let len = u16::from_le_bytes(node.length) as usize;
+
+        if len < core::mem::size_of::<Protocol>()
+            || (node.r#type == TYPE_END && node.sub_type == End::SUBTYPE_ENTIRE)
+        {
+            self.next = core::ptr::null();
+            return None;
+        }
+
+        self.next = self.next.wrapping_add(len);
+        Some(node)
+    }
+}
+
+/// Compute Device-Path Size
+///
+/// This walks the device path starting at `start` and returns its total size in bytes, including
+/// the terminating end-of-entire-path node. Node lengths are stored in little-endian byte order
+/// and include the 4-byte node header. If any node claims a length shorter than its header, the
+/// path is malformed and `None` is returned. `None` is also returned for a null pointer or if the
+/// size overflows `usize`.
+///
+/// # Safety
+///
+/// `start` must either be null or point to a device path that is readable up to and including
+/// its end-of-entire-path node, as far as the encoded node lengths describe it.
+pub unsafe fn device_path_size(start: *const Protocol) -> Option<usize> {
+    let header = core::mem::size_of::<Protocol>();
+    let mut node = start as *const u8;
+    let mut size: usize = 0;
+
+    if node.is_null() {
+        return None;
+    }
+
+    loop {
+        let p = &*(node as *const Protocol);
+        let len = u16::from_le_bytes(p.length) as usize;
+
+        if len < header {
+            return None;
+        }
+
+        size = size.checked_add(len)?;
+
+        if p.r#type == TYPE_END && p.sub_type == End::SUBTYPE_ENTIRE {
+            return Some(size);
+        }
+
+        node = node.add(len);
+    }
+}
+
+/// Append Device-Path Node
+///
+/// This appends a single device node to the device path in `path` and writes the resulting
+/// device path to `out`, without relying on the device-path-utilities protocol. All nodes of
+/// `path` up to its end-of-entire-path node are copied, followed by `node` and a new
+/// end-of-entire-path node. An empty `path` is treated like a path with just an end node. The
+/// size of the resulting path in bytes is returned.
+///
+/// If `path` is malformed (i.e., it lacks an end node or any node length is out of bounds), or
+/// if `node` claims a length shorter than its header, `INVALID_PARAMETER` is returned. If `out`
+/// is too small, `BUFFER_TOO_SMALL` is returned. In both cases `out` is left untouched.
+///
+/// # Safety
+///
+/// `node` must be followed by its payload, such that it is readable for the number of bytes
+/// given by its length field.
+pub unsafe fn append_device_node(
+    path: &[u8],
+    node: &Protocol,
+    out: &mut [u8],
+) -> Result<usize, crate::base::Status> {
+    let header = core::mem::size_of::<Protocol>();
+    let mut prefix = 0;
+
+    if !path.is_empty() {
+        loop {
+            let p = path
+                .get(prefix..prefix + header)
+                .ok_or(crate::base::Status::INVALID_PARAMETER)?;
+            let len = u16::from_le_bytes([p[2], p[3]]) as usize;
+
+            if len < header || prefix + len > path.len() {
+                return Err(crate::base::Status::INVALID_PARAMETER);
+            }
+            if p[0] == TYPE_END && p[1] == End::SUBTYPE_ENTIRE {
+                break;
+            }
+
+            prefix += len;
+        }
+    }
+
+    let len = u16::from_le_bytes(node.length) as usize;
+    if len < header {
+        return Err(crate::base::Status::INVALID_PARAMETER);
+    }
+
+    let total = prefix + len + header;
+    let out = out
+        .get_mut(..total)
+        .ok_or(crate::base::Status::BUFFER_TOO_SMALL)?;
+
+    out[..prefix].copy_from_slice(&path[..prefix]);
+    out[prefix..prefix + len].copy_from_slice(core::slice::from_raw_parts(
+        node as *const Protocol as *const u8,
+        len,
+    ));
+    out[prefix + len..].copy_from_slice(&[TYPE_END, End::SUBTYPE_ENTIRE, header as u8, 0x00]);
+
+    Ok(total)
+}
+
+// Write the header of a node with a payload of `payload` bytes to the start of `buf`, and return
+// the payload area. If the buffer is too small, or the node length exceeds the 16-bit length
+// field, `None` is returned and the buffer is left untouched.
+fn write_node(buf: &mut [u8], r#type: u8, sub_type: u8, payload: usize) -> Option<&mut [u8]> {
+    let header = core::mem::size_of::<Protocol>();
+    let len = payload.checked_add(header)?;
+    let len16 = <u16 as core::convert::TryFrom<usize>>::try_from(len).ok()?;
+    let node = buf.get_mut(..len)?;
+
+    node[0] = r#type;
+    node[1] = sub_type;
+    node[2..4].copy_from_slice(&len16.to_le_bytes());
+    Some(&mut node[header..])
+}
+
+// The messaging-node constructors below encode a single node into the start of a caller-provided
+// buffer. They return the length of the node in bytes, or `None` if the buffer is too small.
+
+/// Encode a USB messaging node for the given parent port and interface number.
+pub fn usb_node(buf: &mut [u8], parent_port: u8, interface: u8) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_USB, 2)?;
+    p[0] = parent_port;
+    p[1] = interface;
+    Some(6)
+}
+
+/// Encode a SATA messaging node. Use `0xffff` as `port_multiplier` if the device is directly
+/// connected to the HBA port.
+pub fn sata_node(buf: &mut [u8], hba_port: u16, port_multiplier: u16, lun: u16) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_SATA, 6)?;
+    p[0..2].copy_from_slice(&hba_port.to_le_bytes());
+    p[2..4].copy_from_slice(&port_multiplier.to_le_bytes());
+    p[4..6].copy_from_slice(&lun.to_le_bytes());
+    Some(10)
+}
+
+/// Encode an NVMe-namespace messaging node. `eui` is the IEEE extended unique identifier of the
+/// namespace, or 0 if it has none.
+pub fn nvme_namespace_node(buf: &mut [u8], nsid: u32, eui: u64) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_NVME_NAMESPACE, 12)?;
+    p[0..4].copy_from_slice(&nsid.to_le_bytes());
+    p[4..12].copy_from_slice(&eui.to_le_bytes());
+    Some(16)
+}
+
+/// Encode a MAC-address messaging node. `if_type` is the network interface type as defined by
+/// RFC 3232 (e.g., 0x01 for Ethernet).
+pub fn mac_node(buf: &mut [u8], mac: &crate::base::MacAddress, if_type: u8) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_MAC, 33)?;
+    p[0..32].copy_from_slice(&mac.addr);
+    p[32] = if_type;
+    Some(37)
+}
+
+/// Encode an IPv4 messaging node. `protocol` is the IP protocol number (e.g., 6 for TCP). If
+/// `static_ip` is false, the local address was assigned via DHCP.
+pub fn ipv4_node(
+    buf: &mut [u8],
+    local: &crate::net::Ipv4Endpoint,
+    remote: &crate::net::Ipv4Endpoint,
+    protocol: u16,
+    static_ip: bool,
+    gateway: &crate::base::Ipv4Address,
+    subnet_mask: &crate::base::Ipv4Address,
+) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_IPV4, 23)?;
+    p[0..4].copy_from_slice(&local.address.addr);
+    p[4..8].copy_from_slice(&remote.address.addr);
+    p[8..10].copy_from_slice(&local.port.to_le_bytes());
+    p[10..12].copy_from_slice(&remote.port.to_le_bytes());
+    p[12..14].copy_from_slice(&protocol.to_le_bytes());
+    p[14] = static_ip as u8;
+    p[15..19].copy_from_slice(&gateway.addr);
+    p[19..23].copy_from_slice(&subnet_mask.addr);
+    Some(27)
+}
+
+/// Encode an IPv6 messaging node. `protocol` is the IP protocol number (e.g., 6 for TCP).
+/// `origin` describes how the local address was assigned (0 for manual, 1 for stateless
+/// auto-configuration, 2 for stateful configuration).
+pub fn ipv6_node(
+    buf: &mut [u8],
+    local: &crate::net::Ipv6Endpoint,
+    remote: &crate::net::Ipv6Endpoint,
+    protocol: u16,
+    origin: u8,
+    prefix_length: u8,
+    gateway: &crate::base::Ipv6Address,
+) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_IPV6, 56)?;
+    p[0..16].copy_from_slice(&local.address.addr);
+    p[16..32].copy_from_slice(&remote.address.addr);
+    p[32..34].copy_from_slice(&local.port.to_le_bytes());
+    p[34..36].copy_from_slice(&remote.port.to_le_bytes());
+    p[36..38].copy_from_slice(&protocol.to_le_bytes());
+    p[38] = origin;
+    p[39] = prefix_length;
+    p[40..56].copy_from_slice(&gateway.addr);
+    Some(60)
+}
+
+/// Encode a URI messaging node. The URI is stored without a terminating 0.
+pub fn uri_node(buf: &mut [u8], uri: &[crate::base::Char8]) -> Option<usize> {
+    let p = write_node(buf, TYPE_MESSAGING, Messaging::SUBTYPE_URI, uri.len())?;
+    p.copy_from_slice(uri);
+    Some(4 + uri.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size_of_paths() {
+        let path: [u8; 14] = [
+            TYPE_HARDWARE,
+            Hardware::SUBTYPE_VENDOR,
+            0x06,
+            0x00,
+            0xaa,
+            0xbb,
+            TYPE_END,
+            End::SUBTYPE_INSTANCE,
+            0x04,
+            0x00,
+            TYPE_END,
+            End::SUBTYPE_ENTIRE,
+            0x04,
+            0x00,
+        ];
+        let bad: [u8; 8] = [
+            TYPE_HARDWARE,
+            Hardware::SUBTYPE_PCI,
+            0x02,
+            0x00,
+            TYPE_END,
+            End::SUBTYPE_ENTIRE,
+            0x04,
+            0x00,
+        ];
+
+        unsafe {
+            assert_eq!(device_path_size(path.as_ptr() as *const Protocol), Some(14));
+            assert_eq!(
+                device_path_size(path[10..].as_ptr() as *const Protocol),
+                Some(4)
+            );
+            assert_eq!(device_path_size(bad.as_ptr() as *const Protocol), None);
+            assert_eq!(device_path_size(core::ptr::null()), None);
+        }
+    }
+
+    #[test]
+    fn walk_nodes() {
+        let path: [u8; 18] = [
+            TYPE_ACPI,
+            Acpi::SUBTYPE_ACPI,
+            0x06,
+            0x00,
+            0xaa,
+            0xbb,
+            TYPE_END,
+            End::SUBTYPE_INSTANCE,
+            0x04,
+            0x00,
+            TYPE_MEDIA,
+            Media::SUBTYPE_FILE_PATH,
+            0x04,
+            0x00,
+            TYPE_END,
+            End::SUBTYPE_ENTIRE,
+            0x04,
+            0x00,
+        ];
+        let nodes: Vec<(u8, u8)> =
+            unsafe { DevicePathNodes::new(path.as_ptr() as *const Protocol) }
+                .map(|v| (v.r#type, v.sub_type))
+                .collect();
+        assert_eq!(
+            nodes,
+            [
+                (TYPE_ACPI, Acpi::SUBTYPE_ACPI),
+                (TYPE_END, End::SUBTYPE_INSTANCE),
+                (TYPE_MEDIA, Media::SUBTYPE_FILE_PATH),
+            ],
+        );
+
+        // Malformed nodes stop the iteration, and so does a null pointer.
+        let bad: [u8; 4] = [TYPE_HARDWARE, Hardware::SUBTYPE_PCI, 0x03, 0x00];
+        assert_eq!(
+            unsafe { DevicePathNodes::new(bad.as_ptr() as *const Protocol) }.count(),
+            0
+        );
+        assert_eq!(
+            unsafe { DevicePathNodes::new(core::ptr::null()) }.count(),
+            0
+        );
+    }
+
+    #[test]
+    fn append_nodes() {
+        let mut usb_buf = [0u8; 6];
+        let mut nvme_buf = [0u8; 16];
+        let mut path = [0u8; 64];
+        let mut next = [0u8; 64];
+
+        assert_eq!(usb_node(&mut usb_buf, 3, 0), Some(6));
+        assert_eq!(nvme_namespace_node(&mut nvme_buf, 1, 0), Some(16));
+
+        unsafe {
+            let usb = &*(usb_buf.as_ptr() as *const Protocol);
+            let nvme = &*(nvme_buf.as_ptr() as *const Protocol);
+
+            assert_eq!(append_device_node(&[], usb, &mut path), Ok(10));
+            assert_eq!(append_device_node(&path[..10], nvme, &mut next), Ok(26));
+            assert_eq!(device_path_size(next.as_ptr() as *const Protocol), Some(26));
+
+            // The path walks from the USB node to the NVMe node and ends thereafter.
+            assert_eq!(next[..6], usb_buf);
+            assert_eq!(
+                (next[6], next[7]),
+                (TYPE_MESSAGING, Messaging::SUBTYPE_NVME_NAMESPACE)
+            );
+            assert_eq!(next[8..10], [16, 0]);
+            assert_eq!(next[22..26], [TYPE_END, End::SUBTYPE_ENTIRE, 4, 0]);
+
+            // Data past the end node is ignored.
+            assert_eq!(append_device_node(&next, usb, &mut path), Ok(32));
+            assert_eq!(device_path_size(path.as_ptr() as *const Protocol), Some(32));
+
+            let mut small = [0u8; 25];
+            assert_eq!(
+                append_device_node(&next[..26], usb, &mut small),
+                Err(crate::base::Status::BUFFER_TOO_SMALL),
+            );
+            assert_eq!(small, [0u8; 25]);
+
+            // Truncated paths, or paths without end node, are rejected.
+            assert_eq!(
+                append_device_node(&next[..22], usb, &mut path),
+                Err(crate::base::Status::INVALID_PARAMETER),
+            );
+            assert_eq!(
+                append_device_node(&next[..24], usb, &mut path),
+                Err(crate::base::Status::INVALID_PARAMETER),
+            );
+        }
+    }
+
+    #[test]
+    fn messaging_nodes() {
+        let mut buf = [0u8; 128];
+
+        fn length(buf: &[u8]) -> usize {
+            assert_eq!(buf[0], TYPE_MESSAGING);
+            u16::from_le_bytes([buf[2], buf[3]]) as usize
+        }
+
+        assert_eq!(usb_node(&mut buf, 1, 2), Some(6));
+        assert_eq!(length(&buf), 6);
+        assert_eq!(buf[1], Messaging::SUBTYPE_USB);
+        assert_eq!(&buf[4..6], &[1, 2]);
+
+        assert_eq!(sata_node(&mut buf, 0x0102, 0xffff, 0), Some(10));
+        assert_eq!(length(&buf), 10);
+        assert_eq!(&buf[4..8], &[0x02, 0x01, 0xff, 0xff]);
+
+        assert_eq!(nvme_namespace_node(&mut buf, 1, 0x1122), Some(16));
+        assert_eq!(length(&buf), 16);
+        assert_eq!(&buf[4..10], &[0x01, 0x00, 0x00, 0x00, 0x22, 0x11]);
+
+        let mac = crate::base::MacAddress { addr: [0xab; 32] };
+        assert_eq!(mac_node(&mut buf, &mac, 0x01), Some(37));
+        assert_eq!(length(&buf), 37);
+        assert_eq!(buf[36], 0x01);
+
+        let v4 = crate::net::Ipv4Endpoint::default();
+        let a4 = crate::base::Ipv4Address::default();
+        assert_eq!(ipv4_node(&mut buf, &v4, &v4, 6, true, &a4, &a4), Some(27));
+        assert_eq!(length(&buf), 27);
+
+        let v6 = crate::net::Ipv6Endpoint::default();
+        let a6 = crate::base::Ipv6Address::default();
+        assert_eq!(ipv6_node(&mut buf, &v6, &v6, 6, 0, 64, &a6), Some(60));
+        assert_eq!(length(&buf), 60);
+
+        assert_eq!(uri_node(&mut buf, b"http://a/b"), Some(14));
+        assert_eq!(length(&buf), 14);
+        assert_eq!(&buf[4..14], b"http://a/b");
+
+        // Short buffers are rejected without modifying them.
+        let mut short = [0u8; 5];
+        assert_eq!(usb_node(&mut short, 1, 2), None);
+        assert_eq!(short, [0u8; 5]);
+        assert_eq!(uri_node(&mut buf, &[0u8; 0x10000]), None);
+    }
+}
@@ -0,0 +1,28 @@
+//! Device Path From Text Protocol
+//!
+//! The device-path-from-text protocol is the counterpart of the device-path-to-text protocol. It
+//! parses the textual representation of device paths and device nodes. The returned device paths
+//! are allocated from pool memory and must be released by the caller via `free_pool()`.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x05c99a21,
+    0xc70f,
+    0x4ad2,
+    0x8a,
+    0x5f,
+    &[0x35, 0xdf, 0x33, 0x43, 0xf5, 0x1e],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub convert_text_to_device_node: eficall! {fn(
+        *const crate::base::Char16,
+    ) -> *mut crate::protocols::device_path::Protocol},
+    pub convert_text_to_device_path: eficall! {fn(
+        *const crate::base::Char16,
+    ) -> *mut crate::protocols::device_path::Protocol},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
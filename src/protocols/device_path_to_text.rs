@@ -0,0 +1,33 @@
+//! Device Path To Text Protocol
+//!
+//! The device-path-to-text protocol converts device paths and individual device nodes into their
+//! textual representation. The returned strings are allocated from pool memory and must be
+//! released by the caller via `free_pool()`. Unlike most protocols, the functions do not take a
+//! pointer to the protocol itself.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8b843e20,
+    0x8132,
+    0x4852,
+    0x90,
+    0xcc,
+    &[0x55, 0x1a, 0x4e, 0x4a, 0x7f, 0x1c],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub convert_device_node_to_text: eficall! {fn(
+        *const crate::protocols::device_path::Protocol,
+        crate::base::Boolean,
+        crate::base::Boolean,
+    ) -> *mut crate::base::Char16},
+    pub convert_device_path_to_text: eficall! {fn(
+        *const crate::protocols::device_path::Protocol,
+        crate::base::Boolean,
+        crate::base::Boolean,
+    ) -> *mut crate::base::Char16},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
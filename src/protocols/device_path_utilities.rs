@@ -45,3 +45,7 @@ pub struct Protocol {
         u16,
     ) -> *mut crate::protocols::device_path::Protocol},
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
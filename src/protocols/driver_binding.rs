@@ -0,0 +1,137 @@
+//! Driver Binding Protocol
+//!
+//! The driver binding protocol is installed by UEFI drivers that follow the UEFI driver model. It
+//! allows the firmware to test whether a driver supports a controller, and to start and stop the
+//! driver on it. `start()` and `supported()` get the remaining device path of the controller,
+//! which is used by bus drivers to create only the requested child.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x18a031ab,
+    0xb443,
+    0x4d1a,
+    0xa5,
+    0xc0,
+    &[0x0c, 0x09, 0x26, 0x1e, 0x9f, 0x71],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub supported: eficall! {fn(
+        *mut Protocol,
+        crate::base::Handle,
+        *mut crate::protocols::device_path::Protocol,
+    ) -> crate::base::Status},
+    pub start: eficall! {fn(
+        *mut Protocol,
+        crate::base::Handle,
+        *mut crate::protocols::device_path::Protocol,
+    ) -> crate::base::Status},
+    pub stop: eficall! {fn(
+        *mut Protocol,
+        crate::base::Handle,
+        usize,
+        *mut crate::base::Handle,
+    ) -> crate::base::Status},
+    pub version: u32,
+    pub image_handle: crate::base::Handle,
+    pub driver_binding_handle: crate::base::Handle,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTROLLER: crate::base::Handle = 0x100 as crate::base::Handle;
+
+    std::thread_local! {
+        static VERSIONS: std::cell::RefCell<Vec<u32>> = const { std::cell::RefCell::new(Vec::new()) };
+        static STOPPED: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn supported(
+        this: *mut Protocol,
+        controller: crate::base::Handle,
+        remaining: *mut crate::protocols::device_path::Protocol,
+    ) -> crate::base::Status {
+        VERSIONS.with(|v| v.borrow_mut().push(unsafe { (*this).version }));
+        if controller != CONTROLLER {
+            return crate::base::Status::UNSUPPORTED;
+        }
+
+        // A bus driver only supports the child selected by the remaining device path, or all
+        // children if it is null.
+        match unsafe { remaining.as_ref() } {
+            None => crate::base::Status::SUCCESS,
+            Some(v) if v.r#type == crate::protocols::device_path::TYPE_HARDWARE => {
+                crate::base::Status::SUCCESS
+            }
+            Some(_) => crate::base::Status::UNSUPPORTED,
+        }
+    }}
+
+    eficall! {fn stop(
+        _this: *mut Protocol,
+        controller: crate::base::Handle,
+        n_children: usize,
+        children: *mut crate::base::Handle,
+    ) -> crate::base::Status {
+        STOPPED.with(|v| v.borrow_mut().push(controller as usize));
+
+        let children = unsafe { core::slice::from_raw_parts(children, n_children) };
+        if children.iter().all(|v| !v.is_null()) {
+            crate::base::Status::SUCCESS
+        } else {
+            crate::base::Status::INVALID_PARAMETER
+        }
+    }}
+
+    #[test]
+    fn callbacks() {
+        let mut driver = Protocol {
+            supported,
+            start: supported,
+            stop,
+            version: 0x10,
+            image_handle: core::ptr::null_mut(),
+            driver_binding_handle: core::ptr::null_mut(),
+        };
+        let p = &mut driver as *mut Protocol;
+
+        let mut hw = crate::protocols::device_path::Protocol {
+            r#type: crate::protocols::device_path::TYPE_HARDWARE,
+            sub_type: 0,
+            length: [4, 0],
+        };
+        let mut end = crate::protocols::device_path::Protocol {
+            r#type: crate::protocols::device_path::TYPE_END,
+            sub_type: crate::protocols::device_path::End::SUBTYPE_ENTIRE,
+            length: [4, 0],
+        };
+
+        assert!(!(driver.supported)(p, CONTROLLER, core::ptr::null_mut()).is_error());
+        assert!(!(driver.start)(p, CONTROLLER, &mut hw).is_error());
+        assert_eq!(
+            (driver.supported)(p, CONTROLLER, &mut end),
+            crate::base::Status::UNSUPPORTED,
+        );
+        assert_eq!(
+            (driver.supported)(p, core::ptr::null_mut(), core::ptr::null_mut()),
+            crate::base::Status::UNSUPPORTED,
+        );
+
+        let mut children = [0x200 as crate::base::Handle, 0x300 as crate::base::Handle];
+        assert!(!(driver.stop)(p, CONTROLLER, 2, children.as_mut_ptr()).is_error());
+        children[1] = core::ptr::null_mut();
+        assert_eq!(
+            (driver.stop)(p, CONTROLLER, 2, children.as_mut_ptr()),
+            crate::base::Status::INVALID_PARAMETER,
+        );
+
+        assert_eq!(VERSIONS.with(|v| v.take()), [0x10; 4]);
+        assert_eq!(STOPPED.with(|v| v.take()), [CONTROLLER as usize; 2]);
+    }
+}
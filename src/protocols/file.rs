@@ -53,9 +53,15 @@ pub struct IoToken {
     pub buffer: *mut core::ffi::c_void,
 }
 
+/// File Info
+///
+/// This is the information returned by `get_info()` for `INFO_ID`. It ends with the file name as
+/// a flexible array of a zero-terminated string. `size` covers the entire structure including the
+/// file name. The `N` parameter allows accessing the name with a compile-time fixed size. It
+/// defaults to `0`, in which case only the header is accessible.
 #[repr(C)]
-#[derive(Debug)]
-pub struct Info {
+#[derive(Clone, Copy, Debug)]
+pub struct Info<const N: usize = 0> {
     pub size: u64,
     pub file_size: u64,
     pub physical_size: u64,
@@ -63,24 +69,38 @@ pub struct Info {
     pub last_access_time: crate::system::Time,
     pub modification_time: crate::system::Time,
     pub attribute: u64,
-    pub file_name: [crate::base::Char16],
+    pub file_name: [crate::base::Char16; N],
 }
 
+/// File System Info
+///
+/// This is the information returned by `get_info()` for `SYSTEM_INFO_ID`. It ends with the
+/// volume label as a flexible array of a zero-terminated string. `size` covers the entire
+/// structure including the label. The `N` parameter allows accessing the label with a
+/// compile-time fixed size. It defaults to `0`, in which case only the header is accessible.
+///
+/// Note that `size_of::<SystemInfo>()` includes trailing padding. The label starts at offset 36,
+/// which is the header size to use when computing buffer sizes.
 #[repr(C)]
-#[derive(Debug)]
-pub struct SystemInfo {
+#[derive(Clone, Copy, Debug)]
+pub struct SystemInfo<const N: usize = 0> {
     pub size: u64,
     pub read_only: crate::base::Boolean,
     pub volume_size: u64,
     pub free_space: u64,
     pub block_size: u32,
-    pub volume_label: [crate::base::Char16],
+    pub volume_label: [crate::base::Char16; N],
 }
 
+/// File System Volume Label
+///
+/// This is the information returned by `get_info()` for `SYSTEM_VOLUME_LABEL_ID`. It consists of
+/// the zero-terminated volume label only, as a flexible array. The `N` parameter works as for
+/// `SystemInfo`.
 #[repr(C)]
-#[derive(Debug)]
-pub struct SystemVolumeLabel {
-    pub volume_label: [crate::base::Char16],
+#[derive(Clone, Copy, Debug)]
+pub struct SystemVolumeLabel<const N: usize = 0> {
+    pub volume_label: [crate::base::Char16; N],
 }
 
 #[repr(C)]
@@ -153,3 +173,23 @@ pub struct Protocol {
         *mut IoToken,
     ) -> crate::base::Status},
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn info_layout() {
+        assert_eq!(core::mem::offset_of!(Info, file_name), 80);
+        assert_eq!(core::mem::size_of::<Info>(), 80);
+        assert_eq!(core::mem::offset_of!(Info<3>, file_name), 80);
+    }
+
+    #[test]
+    fn system_info_layout() {
+        assert_eq!(core::mem::offset_of!(SystemInfo, volume_label), 36);
+        assert_eq!(core::mem::offset_of!(SystemInfo<5>, volume_label), 36);
+        assert_eq!(core::mem::size_of::<SystemVolumeLabel>(), 0);
+        assert_eq!(core::mem::size_of::<SystemVolumeLabel<12>>(), 24);
+    }
+}
@@ -98,3 +98,485 @@ pub struct Protocol {
     ) -> crate::base::Status},
     pub mode: *mut Mode,
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+/// Locate Graphics Output
+///
+/// Systems with several graphics adapters expose the graphics-output protocol on multiple
+/// handles. This enumerates all of them via `locate_handle_buffer()` and picks one. If `largest`
+/// is `false`, the first usable handle is returned. Otherwise, the handle whose current mode has
+/// the largest resolution (i.e., `horizontal_resolution * vertical_resolution`) is returned. If
+/// several handles share the same resolution, the first of them is returned.
+///
+/// Handles that fail to provide the protocol or lack mode information are skipped. If no handle
+/// is usable, `NOT_FOUND` is returned.
+pub fn locate(
+    bs: &crate::system::BootServices,
+    largest: bool,
+) -> Result<(crate::base::Handle, *mut Protocol), crate::base::Status> {
+    let mut guid = PROTOCOL_GUID;
+    let mut n_handles: usize = 0;
+    let mut handles: *mut crate::base::Handle = core::ptr::null_mut();
+    let mut best: Option<(crate::base::Handle, *mut Protocol, u64)> = None;
+
+    let r = (bs.locate_handle_buffer)(
+        crate::system::LocateSearchType::ByProtocol,
+        &mut guid,
+        core::ptr::null_mut(),
+        &mut n_handles,
+        &mut handles,
+    );
+    if r.is_error() {
+        return Err(r);
+    }
+
+    for i in 0..n_handles {
+        let handle = unsafe { *handles.add(i) };
+        let mut interface: *mut core::ffi::c_void = core::ptr::null_mut();
+
+        let r = (bs.handle_protocol)(handle, &mut guid, &mut interface);
+        if r.is_error() || interface.is_null() {
+            continue;
+        }
+
+        let gop = interface as *mut Protocol;
+        let info = unsafe {
+            let mode = (*gop).mode;
+            if mode.is_null() || (*mode).info.is_null() {
+                continue;
+            }
+            &*(*mode).info
+        };
+        let size = info.horizontal_resolution as u64 * info.vertical_resolution as u64;
+
+        match best {
+            Some((_, _, best_size)) if best_size >= size => {}
+            _ => best = Some((handle, gop, size)),
+        }
+
+        if !largest {
+            break;
+        }
+    }
+
+    (bs.free_pool)(handles as *mut core::ffi::c_void);
+
+    match best {
+        Some((handle, gop, _)) => Ok((handle, gop)),
+        None => Err(crate::base::Status::NOT_FOUND),
+    }
+}
+
+/// Find Graphics Mode
+///
+/// This enumerates all modes of `gop` via `query_mode()` and returns the number of the mode with
+/// a resolution of `width` times `height`. If no mode matches exactly and `nearest` is `true`,
+/// the mode closest to the requested resolution is returned instead, measured as the sum of the
+/// squared differences of both dimensions. Ties are resolved in favor of the lower mode number.
+///
+/// The mode information returned by `query_mode()` is allocated by the firmware and released via
+/// `free_pool()` of `bs`. Modes that fail to be queried are skipped.
+pub fn find_mode(
+    bs: &crate::system::BootServices,
+    gop: &mut Protocol,
+    width: u32,
+    height: u32,
+    nearest: bool,
+) -> Option<u32> {
+    let max_mode = match unsafe { gop.mode.as_ref() } {
+        Some(mode) => mode.max_mode,
+        None => return None,
+    };
+    let mut best: Option<(u32, u128)> = None;
+
+    for i in 0..max_mode {
+        let mut size: usize = 0;
+        let mut info: *mut ModeInformation = core::ptr::null_mut();
+
+        let r = (gop.query_mode)(gop, i, &mut size, &mut info);
+        if r.is_error() || info.is_null() {
+            continue;
+        }
+
+        let (w, h) = unsafe { ((*info).horizontal_resolution, (*info).vertical_resolution) };
+        (bs.free_pool)(info as *mut core::ffi::c_void);
+
+        // The squares of two `u32` differences do not fit into `u64` in the worst case.
+        let dw = (w as i64 - width as i64).unsigned_abs() as u128;
+        let dh = (h as i64 - height as i64).unsigned_abs() as u128;
+        let distance = dw * dw + dh * dh;
+
+        if distance == 0 {
+            return Some(i);
+        }
+
+        match best {
+            Some((_, best_distance)) if best_distance <= distance => {}
+            _ => best = Some((i, distance)),
+        }
+    }
+
+    if nearest {
+        best.map(|(i, _)| i)
+    } else {
+        None
+    }
+}
+
+/// Off-Screen Frame Buffer
+///
+/// This wraps a caller-provided buffer of `BltPixel` values as an off-screen canvas of a fixed
+/// width and height. Pixels are stored row by row, without padding between rows. Once composed,
+/// the entire canvas can be copied to the screen with a single `BltBufferToVideo` operation via
+/// `present()`, which avoids flicker caused by drawing directly to video memory.
+pub struct FrameBuffer<'a> {
+    pixels: &'a mut [BltPixel],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> FrameBuffer<'a> {
+    /// Create a frame buffer of `width` times `height` pixels, backed by `pixels`. Returns `None`
+    /// if the backing buffer is too small. Excess pixels of the buffer are left unused.
+    pub fn new(pixels: &'a mut [BltPixel], width: usize, height: usize) -> Option<Self> {
+        match width.checked_mul(height) {
+            Some(n) if n <= pixels.len() => Some(FrameBuffer {
+                pixels,
+                width,
+                height,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Return the pixel at the given coordinates, or `None` if they are out of bounds.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<BltPixel> {
+        self.index(x, y).map(|i| self.pixels[i])
+    }
+
+    /// Set the pixel at the given coordinates. Out-of-bounds coordinates are ignored.
+    pub fn put_pixel(&mut self, x: usize, y: usize, pixel: BltPixel) {
+        if let Some(i) = self.index(x, y) {
+            self.pixels[i] = pixel;
+        }
+    }
+
+    /// Fill a rectangle with the given pixel. The rectangle is clipped to the frame buffer.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, pixel: BltPixel) {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+
+        for row in y.min(y_end)..y_end {
+            let start = row * self.width;
+            self.pixels[start + x.min(x_end)..start + x_end].fill(pixel);
+        }
+    }
+
+    /// Copy the entire frame buffer to the top-left corner of the screen of `gop`.
+    pub fn present(&mut self, gop: &mut Protocol) -> Result<(), crate::base::Status> {
+        let r = (gop.blt)(
+            gop,
+            self.pixels.as_mut_ptr(),
+            BltOperation::BltBufferToVideo,
+            0,
+            0,
+            0,
+            0,
+            self.width,
+            self.height,
+            self.width * core::mem::size_of::<BltPixel>(),
+        );
+        if r.is_error() {
+            return Err(r);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    std::thread_local! {
+        static HANDLES: RefCell<Vec<crate::base::Handle>> = const { RefCell::new(Vec::new()) };
+        static SEARCHES: RefCell<Vec<(crate::system::LocateSearchType, crate::base::Guid)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn locate_handle_buffer(
+        search: crate::system::LocateSearchType,
+        guid: *mut crate::base::Guid,
+        _key: *mut core::ffi::c_void,
+        n_handles: *mut usize,
+        handles: *mut *mut crate::base::Handle,
+    ) -> crate::base::Status {
+        SEARCHES.with(|v| v.borrow_mut().push((search, unsafe { *guid })));
+
+        HANDLES.with(|v| {
+            let mut v = v.borrow_mut();
+            unsafe {
+                *n_handles = v.len();
+                *handles = v.as_mut_ptr();
+            }
+        });
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn handle_protocol(
+        handle: crate::base::Handle,
+        _guid: *mut crate::base::Guid,
+        interface: *mut *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        // The mocked handles point directly to their protocol.
+        unsafe { *interface = handle };
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn free_pool(_buffer: *mut core::ffi::c_void) -> crate::base::Status {
+        crate::base::Status::SUCCESS
+    }}
+
+    fn mode_information(width: u32, height: u32) -> ModeInformation {
+        ModeInformation {
+            version: 0,
+            horizontal_resolution: width,
+            vertical_resolution: height,
+            pixel_format: GraphicsPixelFormat::PixelBlueGreenRedReserved8BitPerColor,
+            pixel_information: PixelBitmask {
+                red_mask: 0,
+                green_mask: 0,
+                blue_mask: 0,
+                reserved_mask: 0,
+            },
+            pixels_per_scan_line: width,
+        }
+    }
+
+    fn mode(info: &mut ModeInformation) -> Mode {
+        Mode {
+            max_mode: 1,
+            mode: 0,
+            info,
+            size_of_info: core::mem::size_of::<ModeInformation>(),
+            frame_buffer_base: 0,
+            frame_buffer_size: 0,
+        }
+    }
+
+    fn protocol(mode: &mut Mode) -> Protocol {
+        Protocol {
+            query_mode: unsafe { crate::mock::stub() },
+            set_mode: unsafe { crate::mock::stub() },
+            blt: unsafe { crate::mock::stub() },
+            mode,
+        }
+    }
+
+    std::thread_local! {
+        static RESOLUTIONS: RefCell<Vec<(u32, u32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn query_mode(
+        _this: *mut Protocol,
+        mode: u32,
+        size: *mut usize,
+        info: *mut *mut ModeInformation,
+    ) -> crate::base::Status {
+        let (width, height) = match RESOLUTIONS.with(|v| v.borrow().get(mode as usize).copied()) {
+            Some(v) => v,
+            None => return crate::base::Status::INVALID_PARAMETER,
+        };
+        if width == 0 {
+            return crate::base::Status::DEVICE_ERROR;
+        }
+
+        let bs = crate::mock::boot_services_with_allocator();
+        let mut p: *mut core::ffi::c_void = core::ptr::null_mut();
+
+        (bs.allocate_pool)(
+            crate::system::MemoryType::BootServicesData,
+            core::mem::size_of::<ModeInformation>(),
+            &mut p,
+        );
+        unsafe {
+            core::ptr::write(p as *mut ModeInformation, mode_information(width, height));
+            *size = core::mem::size_of::<ModeInformation>();
+            *info = p as *mut ModeInformation;
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn find_modes() {
+        let tracker = crate::mock::AllocationTracker::new();
+        let bs = crate::mock::boot_services_with_allocator();
+
+        RESOLUTIONS.with(|v| {
+            *v.borrow_mut() = vec![(640, 480), (0, 0), (1024, 768), (1920, 1080), (1280, 1024)];
+        });
+
+        let mut info = mode_information(640, 480);
+        let mut m = mode(&mut info);
+        m.max_mode = 5;
+        let mut gop = protocol(&mut m);
+        gop.query_mode = query_mode;
+
+        assert_eq!(find_mode(&bs, &mut gop, 1024, 768, false), Some(2));
+        assert_eq!(find_mode(&bs, &mut gop, 1920, 1080, true), Some(3));
+        assert_eq!(find_mode(&bs, &mut gop, 1280, 800, false), None);
+        assert_eq!(find_mode(&bs, &mut gop, 1100, 800, true), Some(2));
+        assert_eq!(find_mode(&bs, &mut gop, 1300, 1000, true), Some(4));
+        assert_eq!(find_mode(&bs, &mut gop, 0, 0, true), Some(0));
+        assert_eq!(find_mode(&bs, &mut gop, u32::MAX, u32::MAX, true), Some(3));
+        assert_eq!(find_mode(&bs, &mut gop, u32::MAX, 0, true), Some(3));
+        assert_eq!(find_mode(&bs, &mut gop, 0, u32::MAX, true), Some(3));
+
+        // All mode information must have been released.
+        assert!(tracker.leaks().is_empty());
+
+        gop.mode = core::ptr::null_mut();
+        assert_eq!(find_mode(&bs, &mut gop, 640, 480, true), None);
+
+        RESOLUTIONS.with(|v| v.borrow_mut().clear());
+    }
+
+    #[test]
+    fn locate_multiple() {
+        let mut bs = crate::mock::boot_services();
+        bs.locate_handle_buffer = locate_handle_buffer;
+        bs.handle_protocol = handle_protocol;
+        bs.free_pool = free_pool;
+
+        let mut infos = [
+            mode_information(800, 600),
+            mode_information(1920, 1080),
+            mode_information(1024, 768),
+        ];
+        let mut modes: Vec<Mode> = infos.iter_mut().map(mode).collect();
+        let mut gops: Vec<Protocol> = modes.iter_mut().map(protocol).collect();
+        let ptrs: Vec<*mut Protocol> = gops.iter_mut().map(|v| v as *mut Protocol).collect();
+
+        HANDLES.with(|v| {
+            *v.borrow_mut() = ptrs.iter().map(|v| *v as crate::base::Handle).collect();
+        });
+
+        let (handle, gop) = locate(&bs, false).unwrap();
+        assert_eq!(handle, ptrs[0] as crate::base::Handle);
+        assert_eq!(gop, ptrs[0]);
+
+        let (handle, gop) = locate(&bs, true).unwrap();
+        assert_eq!(handle, ptrs[1] as crate::base::Handle);
+        assert_eq!(gop, ptrs[1]);
+
+        HANDLES.with(|v| v.borrow_mut().clear());
+
+        assert_eq!(locate(&bs, true), Err(crate::base::Status::NOT_FOUND));
+
+        let searches = SEARCHES.with(|v| v.take());
+        assert_eq!(searches.len(), 3);
+        assert!(searches.iter().all(|(search, guid)| {
+            matches!(search, crate::system::LocateSearchType::ByProtocol) && *guid == PROTOCOL_GUID
+        }));
+    }
+
+    std::thread_local! {
+        static BLTS: RefCell<Vec<(BltOperation, [usize; 7], BltPixel)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn blt(
+        _this: *mut Protocol,
+        buffer: *mut BltPixel,
+        operation: BltOperation,
+        src_x: usize,
+        src_y: usize,
+        dst_x: usize,
+        dst_y: usize,
+        width: usize,
+        height: usize,
+        delta: usize,
+    ) -> crate::base::Status {
+        let args = [src_x, src_y, dst_x, dst_y, width, height, delta];
+        BLTS.with(|v| v.borrow_mut().push((operation, args, unsafe { *buffer.add(5) })));
+        crate::base::Status::SUCCESS
+    }}
+
+    const BLACK: BltPixel = BltPixel {
+        blue: 0x00,
+        green: 0x00,
+        red: 0x00,
+        reserved: 0x00,
+    };
+    const RED: BltPixel = BltPixel {
+        blue: 0x00,
+        green: 0x00,
+        red: 0xff,
+        reserved: 0x00,
+    };
+
+    #[test]
+    fn frame_buffer() {
+        let mut pixels = [BLACK; 13];
+
+        assert!(FrameBuffer::new(&mut pixels, 4, 4).is_none());
+        assert!(FrameBuffer::new(&mut pixels, usize::MAX, 2).is_none());
+
+        let mut fb = FrameBuffer::new(&mut pixels, 4, 3).unwrap();
+
+        // Pixels are addressed row by row, without padding.
+        fb.put_pixel(1, 1, RED);
+        fb.put_pixel(4, 0, RED);
+        fb.put_pixel(0, 3, RED);
+        assert_eq!(fb.pixel(1, 1).unwrap().red, 0xff);
+        assert!(fb.pixel(4, 0).is_none());
+        assert!(fb.pixel(0, 3).is_none());
+
+        // Rectangles are clipped to the frame buffer.
+        fb.fill_rect(3, 2, 10, 10, RED);
+        fb.fill_rect(5, 0, 1, 1, RED);
+        fb.fill_rect(0, 5, 1, 1, RED);
+
+        let mut gop = Protocol {
+            query_mode: unsafe { crate::mock::stub() },
+            set_mode: unsafe { crate::mock::stub() },
+            blt,
+            mode: core::ptr::null_mut(),
+        };
+        assert_eq!(fb.present(&mut gop), Ok(()));
+
+        let blts = BLTS.with(|v| v.take());
+        assert_eq!(blts.len(), 1);
+        assert!(matches!(blts[0].0, BltOperation::BltBufferToVideo));
+        assert_eq!(blts[0].1, [0, 0, 0, 0, 4, 3, 16]);
+        assert_eq!(blts[0].2.red, 0xff);
+
+        let reds: Vec<usize> = pixels
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.red == 0xff)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(reds, [5, 11]);
+    }
+}
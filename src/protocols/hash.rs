@@ -0,0 +1,134 @@
+//! Hash Protocol
+//!
+//! The hash protocol computes message digests with one of several algorithms, each identified by
+//! a GUID. Instances are created via the hash service-binding protocol. The digest types and
+//! algorithm GUIDs are shared with the hash2 protocol.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xc5184932,
+    0xdba5,
+    0x46db,
+    0xa5,
+    0xba,
+    &[0xcc, 0x0b, 0xda, 0x9c, 0x14, 0x35],
+);
+
+pub const SERVICE_BINDING_PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x42881c98,
+    0xa4f3,
+    0x44b0,
+    0xa3,
+    0x9d,
+    &[0xdf, 0xa1, 0x86, 0x67, 0xd8, 0xcd],
+);
+
+pub const ALGORITHM_SHA1_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x2ae9d80f,
+    0x3fb2,
+    0x4095,
+    0xb7,
+    0xb1,
+    &[0xe9, 0x31, 0x57, 0xb9, 0x46, 0xb6],
+);
+
+pub const ALGORITHM_SHA224_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8df01a06,
+    0x9bd5,
+    0x4bf7,
+    0xb0,
+    0x21,
+    &[0xdb, 0x4f, 0xd9, 0xcc, 0xf4, 0x5b],
+);
+
+pub const ALGORITHM_SHA256_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x51aa59de,
+    0xfdf2,
+    0x4ea3,
+    0xbc,
+    0x63,
+    &[0x87, 0x5f, 0xb7, 0x84, 0x2e, 0xe9],
+);
+
+pub const ALGORITHM_SHA384_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xefa96432,
+    0xde33,
+    0x4dd2,
+    0xae,
+    0xe6,
+    &[0x32, 0x8c, 0x33, 0xdf, 0x77, 0x7a],
+);
+
+pub const ALGORITHM_SHA512_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xcaa4381e,
+    0x750c,
+    0x4770,
+    0xb8,
+    0x70,
+    &[0x7a, 0x23, 0xb4, 0xe4, 0x21, 0x30],
+);
+
+pub const ALGORITHM_MD5_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x0af7c79c,
+    0x65b5,
+    0x4319,
+    0xb0,
+    0xae,
+    &[0x44, 0xec, 0x48, 0x4e, 0x4a, 0xd7],
+);
+
+pub const ALGORITHM_SHA1_NOPAD_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x24c5dc2f,
+    0x53e2,
+    0x40ca,
+    0x9e,
+    0xd6,
+    &[0xa5, 0xd9, 0xa4, 0x9f, 0x46, 0x3b],
+);
+
+pub const ALGORITHM_SHA256_NOPAD_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8628752a,
+    0x6cb7,
+    0x4814,
+    0x96,
+    0xfc,
+    &[0x24, 0xa8, 0x15, 0xac, 0x22, 0x26],
+);
+
+pub type Md5Hash = [u8; 16];
+pub type Sha1Hash = [u8; 20];
+pub type Sha224Hash = [u8; 28];
+pub type Sha256Hash = [u8; 32];
+pub type Sha384Hash = [u8; 48];
+pub type Sha512Hash = [u8; 64];
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Output {
+    pub md5_hash: *mut Md5Hash,
+    pub sha1_hash: *mut Sha1Hash,
+    pub sha224_hash: *mut Sha224Hash,
+    pub sha256_hash: *mut Sha256Hash,
+    pub sha384_hash: *mut Sha384Hash,
+    pub sha512_hash: *mut Sha512Hash,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_hash_size: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Guid,
+        *mut usize,
+    ) -> crate::base::Status},
+    pub hash: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Guid,
+        crate::base::Boolean,
+        *mut u8,
+        u64,
+        *mut Output,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
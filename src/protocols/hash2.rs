@@ -0,0 +1,68 @@
+//! Hash2 Protocol
+//!
+//! The hash2 protocol is the successor of the hash protocol. Besides hashing a message in one
+//! go, it supports incremental hashing via `hash_init()`, `hash_update()`, and `hash_final()`.
+//! It uses the algorithm GUIDs and digest types of the hash protocol, but returns digests by
+//! value rather than via pointers.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x55b1d734,
+    0xc5e1,
+    0x49db,
+    0x96,
+    0x47,
+    &[0xb1, 0x6a, 0xfb, 0x0e, 0x30, 0x5b],
+);
+
+pub const SERVICE_BINDING_PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xda836f8d,
+    0x217f,
+    0x4ca0,
+    0x99,
+    0xc2,
+    &[0x1c, 0xa4, 0xe1, 0x60, 0x77, 0xea],
+);
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Output {
+    pub md5_hash: crate::protocols::hash::Md5Hash,
+    pub sha1_hash: crate::protocols::hash::Sha1Hash,
+    pub sha224_hash: crate::protocols::hash::Sha224Hash,
+    pub sha256_hash: crate::protocols::hash::Sha256Hash,
+    pub sha384_hash: crate::protocols::hash::Sha384Hash,
+    pub sha512_hash: crate::protocols::hash::Sha512Hash,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_hash_size: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Guid,
+        *mut usize,
+    ) -> crate::base::Status},
+    pub hash: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Guid,
+        *mut u8,
+        usize,
+        *mut Output,
+    ) -> crate::base::Status},
+    pub hash_init: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Guid,
+    ) -> crate::base::Status},
+    pub hash_update: eficall! {fn(
+        *mut Protocol,
+        *mut u8,
+        usize,
+    ) -> crate::base::Status},
+    pub hash_final: eficall! {fn(
+        *mut Protocol,
+        *mut Output,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -0,0 +1,115 @@
+//! IPv6 Configuration Protocol
+//!
+//! The IPv6 configuration protocol manages the configuration of the EFI IPv6 network stack. It
+//! provides access to the interface information, the configuration policy, manual addresses,
+//! gateways and DNS servers of a single network interface.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x937fe521,
+    0x95ae,
+    0x4d1a,
+    0x89,
+    0x29,
+    &[0x48, 0xbc, 0xd9, 0x0a, 0xd3, 0x1a],
+);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum DataType {
+    DataTypeInterfaceInfo,
+    DataTypeAltInterfaceId,
+    DataTypePolicy,
+    DataTypeDupAddrDetectTransmits,
+    DataTypeManualAddress,
+    DataTypeGateway,
+    DataTypeDnsServer,
+    DataTypeMaximum,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum Policy {
+    PolicyManual,
+    PolicyAutomatic,
+}
+
+// XXX: `AddressInfo` and `RouteTable` are defined by the IPv6 protocol. Move them there once it
+//      is available.
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AddressInfo {
+    pub address: crate::base::Ipv6Address,
+    pub prefix_length: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RouteTable {
+    pub gateway: crate::base::Ipv6Address,
+    pub destination: crate::base::Ipv6Address,
+    pub prefix_length: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct InterfaceInfo {
+    pub name: [crate::base::Char16; 32],
+    pub if_type: u8,
+    pub hw_address_size: u32,
+    pub hw_address: crate::base::MacAddress,
+    pub address_info_count: u32,
+    pub address_info: *mut AddressInfo,
+    pub route_count: u32,
+    pub route_table: *mut RouteTable,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InterfaceId {
+    pub id: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DupAddrDetectTransmits {
+    pub dup_addr_detect_transmits: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct ManualAddress {
+    pub address: crate::base::Ipv6Address,
+    pub is_anycast: crate::base::Boolean,
+    pub prefix_length: u8,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub set_data: eficall! {fn(
+        *mut Protocol,
+        DataType,
+        usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub get_data: eficall! {fn(
+        *mut Protocol,
+        DataType,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub register_data_notify: eficall! {fn(
+        *mut Protocol,
+        DataType,
+        crate::base::Event,
+    ) -> crate::base::Status},
+    pub unregister_data_notify: eficall! {fn(
+        *mut Protocol,
+        DataType,
+        crate::base::Event,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
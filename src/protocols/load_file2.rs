@@ -0,0 +1,29 @@
+//! Load File 2 Protocol
+//!
+//! The load file 2 protocol has the same interface as the load file protocol, but is not used by
+//! the boot manager to load boot options. Instead, it is used to provide files to other loaders,
+//! for instance the initrd to the Linux EFI stub. `boot_policy` must always be `FALSE`.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x4006c0c1,
+    0xfcb3,
+    0x403e,
+    0x99,
+    0x6d,
+    &[0x4a, 0x6c, 0x87, 0x24, 0xe0, 0x6d],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub load_file: eficall! {fn(
+        *mut Protocol,
+        *mut crate::protocols::device_path::Protocol,
+        crate::base::Boolean,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -12,6 +12,15 @@ pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     &[0x00, 0xa0, 0xc9, 0x69, 0x72, 0x3b],
 );
 
+/// Loaded Image Device Path Protocol GUID
+///
+/// The loaded-image device-path protocol is installed on the same image handle as the
+/// loaded-image protocol. Its interface is a plain `device_path::Protocol` pointer to the full
+/// device path the image was loaded from (e.g., disk, partition, and file path). This is an alias
+/// of `loaded_image_device_path::PROTOCOL_GUID`.
+pub const DEVICE_PATH_PROTOCOL_GUID: crate::base::Guid =
+    crate::protocols::loaded_image_device_path::PROTOCOL_GUID;
+
 pub const REVISION: u32 = 0x00001000u32;
 
 #[repr(C)]
@@ -35,3 +44,7 @@ pub struct Protocol {
         crate::base::Handle,
     ) -> crate::base::Status},
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
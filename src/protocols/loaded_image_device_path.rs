@@ -2,6 +2,10 @@
 //!
 //! The loaded image device path protocol provides the device path of a loaded image, using the
 //! protocol structures of the device-path and loaded-image protocols.
+//!
+//! There is no dedicated protocol structure. The protocol interface is a pointer to the first
+//! node of the device path (i.e., a `device_path::Protocol`). Unlike the `file_path` member of the
+//! loaded-image protocol, this is the full path including the device the image was loaded from.
 
 pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     0xbc62157e,
@@ -0,0 +1,26 @@
+//! Metronome Architectural Protocol
+//!
+//! The metronome architectural protocol is defined by the PI specification. It provides a way to
+//! wait for a number of fixed-length ticks, which the DXE core uses to implement `stall()`.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x26baccb2,
+    0x6f42,
+    0x11d4,
+    0xbc,
+    0xe7,
+    &[0x00, 0x80, 0xc7, 0x3c, 0x88, 0x81],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub wait_for_tick: eficall! {fn(
+        *mut Protocol,
+        u32,
+    ) -> crate::base::Status},
+    pub tick_period: u32,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
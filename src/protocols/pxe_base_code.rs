@@ -0,0 +1,350 @@
+//! PXE Base Code Protocol
+//!
+//! The PXE base-code protocol provides the network services required for booting via the Preboot
+//! Execution Environment (PXE). This includes DHCP and PXE discovery, (M)TFTP transfers, and raw
+//! UDP I/O. The protocol stores the packets it received in its mode structure.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x03c4e603,
+    0xac28,
+    0x11d3,
+    0x9a,
+    0x2d,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub const REVISION: u64 = 0x0000000000010000u64;
+
+pub const MAX_IPCNT: usize = 8;
+pub const MAX_ARP_ENTRIES: usize = 8;
+pub const MAX_ROUTE_ENTRIES: usize = 8;
+
+pub const IP_FILTER_STATION_IP: u8 = 0x01u8;
+pub const IP_FILTER_BROADCAST: u8 = 0x02u8;
+pub const IP_FILTER_PROMISCUOUS: u8 = 0x04u8;
+pub const IP_FILTER_PROMISCUOUS_MULTICAST: u8 = 0x08u8;
+
+pub const UDP_OPFLAGS_ANY_SRC_IP: u16 = 0x0001u16;
+pub const UDP_OPFLAGS_ANY_SRC_PORT: u16 = 0x0002u16;
+pub const UDP_OPFLAGS_ANY_DEST_IP: u16 = 0x0004u16;
+pub const UDP_OPFLAGS_ANY_DEST_PORT: u16 = 0x0008u16;
+pub const UDP_OPFLAGS_USE_FILTER: u16 = 0x0010u16;
+pub const UDP_OPFLAGS_MAY_FRAGMENT: u16 = 0x0020u16;
+
+pub const BOOT_TYPE_BOOTSTRAP: u16 = 0x0000u16;
+pub const BOOT_TYPE_MS_WINNT_RIS: u16 = 0x0001u16;
+pub const BOOT_TYPE_INTEL_LCM: u16 = 0x0002u16;
+pub const BOOT_TYPE_DOSUNDI: u16 = 0x0003u16;
+pub const BOOT_TYPE_NEC_ESMPRO: u16 = 0x0004u16;
+pub const BOOT_TYPE_IBM_WSOD: u16 = 0x0005u16;
+pub const BOOT_TYPE_IBM_LCCM: u16 = 0x0006u16;
+pub const BOOT_TYPE_CA_UNICENTER_TNG: u16 = 0x0007u16;
+pub const BOOT_TYPE_HP_OPENVIEW: u16 = 0x0008u16;
+pub const BOOT_TYPE_ALTIRIS_9: u16 = 0x0009u16;
+pub const BOOT_TYPE_ALTIRIS_10: u16 = 0x000au16;
+pub const BOOT_TYPE_ALTIRIS_11: u16 = 0x000bu16;
+pub const BOOT_TYPE_REDHAT_INSTALL: u16 = 0x000du16;
+pub const BOOT_TYPE_REDHAT_BOOT: u16 = 0x000eu16;
+pub const BOOT_TYPE_REMBO: u16 = 0x000fu16;
+pub const BOOT_TYPE_BEOBOOT: u16 = 0x0010u16;
+pub const BOOT_TYPE_PXETEST: u16 = 0xffffu16;
+
+pub type UdpPort = u16;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Dhcpv4Packet {
+    pub bootp_opcode: u8,
+    pub bootp_hw_type: u8,
+    pub bootp_hw_addr_len: u8,
+    pub bootp_gate_hops: u8,
+    pub bootp_ident: u32,
+    pub bootp_seconds: u16,
+    pub bootp_flags: u16,
+    pub bootp_ci_addr: [u8; 4],
+    pub bootp_yi_addr: [u8; 4],
+    pub bootp_si_addr: [u8; 4],
+    pub bootp_gi_addr: [u8; 4],
+    pub bootp_hw_addr: [u8; 16],
+    pub bootp_srv_name: [u8; 64],
+    pub bootp_boot_file: [u8; 128],
+    pub dhcp_magik: u32,
+    pub dhcp_options: [u8; 56],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Dhcpv6Packet {
+    // XXX: The specification declares these as 8-bit and 24-bit bit-fields of a 32-bit integer.
+    //      This is the equivalent little-endian layout, but only 1-byte aligned.
+    pub message_type: u8,
+    pub transaction_id: [u8; 3],
+    pub dhcp_options: [u8; 1024],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union Packet {
+    pub raw: [u8; 1472],
+    pub dhcpv4: Dhcpv4Packet,
+    pub dhcpv6: Dhcpv6Packet,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct IcmpErrorEcho {
+    pub identifier: u16,
+    pub sequence: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union IcmpErrorU {
+    pub reserved: u32,
+    pub mtu: u32,
+    pub pointer: u32,
+    pub echo: IcmpErrorEcho,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct IcmpError {
+    pub r#type: u8,
+    pub code: u8,
+    pub checksum: u16,
+    pub u: IcmpErrorU,
+    pub data: [u8; 494],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct TftpError {
+    pub error_code: u8,
+    pub error_string: [crate::base::Char8; 127],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct IpFilter {
+    pub filters: u8,
+    pub ip_cnt: u8,
+    pub reserved: u16,
+    pub ip_list: [crate::base::IpAddress; MAX_IPCNT],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ArpEntry {
+    pub ip_addr: crate::base::IpAddress,
+    pub mac_addr: crate::base::MacAddress,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RouteEntry {
+    pub ip_addr: crate::base::IpAddress,
+    pub subnet_mask: crate::base::IpAddress,
+    pub gw_addr: crate::base::IpAddress,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Mode {
+    pub started: crate::base::Boolean,
+    pub ipv6_available: crate::base::Boolean,
+    pub ipv6_supported: crate::base::Boolean,
+    pub using_ipv6: crate::base::Boolean,
+    pub bis_supported: crate::base::Boolean,
+    pub bis_detected: crate::base::Boolean,
+    pub auto_arp: crate::base::Boolean,
+    pub send_guid: crate::base::Boolean,
+    pub dhcp_discover_valid: crate::base::Boolean,
+    pub dhcp_ack_received: crate::base::Boolean,
+    pub proxy_offer_received: crate::base::Boolean,
+    pub pxe_discover_valid: crate::base::Boolean,
+    pub pxe_reply_received: crate::base::Boolean,
+    pub pxe_bis_reply_received: crate::base::Boolean,
+    pub icmp_error_received: crate::base::Boolean,
+    pub tftp_error_received: crate::base::Boolean,
+    pub make_callbacks: crate::base::Boolean,
+    pub ttl: u8,
+    pub tos: u8,
+    pub station_ip: crate::base::IpAddress,
+    pub subnet_mask: crate::base::IpAddress,
+    pub dhcp_discover: Packet,
+    pub dhcp_ack: Packet,
+    pub proxy_offer: Packet,
+    pub pxe_discover: Packet,
+    pub pxe_reply: Packet,
+    pub pxe_bis_reply: Packet,
+    pub ip_filter: IpFilter,
+    pub arp_cache_entries: u32,
+    pub arp_cache: [ArpEntry; MAX_ARP_ENTRIES],
+    pub route_table_entries: u32,
+    pub route_table: [RouteEntry; MAX_ROUTE_ENTRIES],
+    pub icmp_error: IcmpError,
+    pub tftp_error: TftpError,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SrvList {
+    pub r#type: u16,
+    pub accept_any_response: crate::base::Boolean,
+    pub reserved: u8,
+    pub ip_addr: crate::base::IpAddress,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DiscoverInfo<const N: usize = 0> {
+    pub use_m_cast: crate::base::Boolean,
+    pub use_b_cast: crate::base::Boolean,
+    pub use_u_cast: crate::base::Boolean,
+    pub must_use_list: crate::base::Boolean,
+    pub server_m_cast_ip: crate::base::IpAddress,
+    pub ip_cnt: u16,
+    pub srv_list: [SrvList; N],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MtftpInfo {
+    pub m_cast_ip: crate::base::IpAddress,
+    pub c_port: UdpPort,
+    pub s_port: UdpPort,
+    pub listen_timeout: u16,
+    pub transmit_timeout: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum TftpOpcode {
+    TftpFirst,
+    TftpGetFileSize,
+    TftpReadFile,
+    TftpWriteFile,
+    TftpReadDirectory,
+    MtftpGetFileSize,
+    MtftpReadFile,
+    MtftpReadDirectory,
+    MtftpLast,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub revision: u64,
+    pub start: eficall! {fn(
+        *mut Protocol,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub stop: eficall! {fn(
+        *mut Protocol,
+    ) -> crate::base::Status},
+    pub dhcp: eficall! {fn(
+        *mut Protocol,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub discover: eficall! {fn(
+        *mut Protocol,
+        u16,
+        *mut u16,
+        crate::base::Boolean,
+        *mut DiscoverInfo,
+    ) -> crate::base::Status},
+    pub mtftp: eficall! {fn(
+        *mut Protocol,
+        TftpOpcode,
+        *mut core::ffi::c_void,
+        crate::base::Boolean,
+        *mut u64,
+        *mut usize,
+        *mut crate::base::IpAddress,
+        *mut crate::base::Char8,
+        *mut MtftpInfo,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub udp_write: eficall! {fn(
+        *mut Protocol,
+        u16,
+        *mut crate::base::IpAddress,
+        *mut UdpPort,
+        *mut crate::base::IpAddress,
+        *mut crate::base::IpAddress,
+        *mut UdpPort,
+        *mut usize,
+        *mut core::ffi::c_void,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub udp_read: eficall! {fn(
+        *mut Protocol,
+        u16,
+        *mut crate::base::IpAddress,
+        *mut UdpPort,
+        *mut crate::base::IpAddress,
+        *mut UdpPort,
+        *mut usize,
+        *mut core::ffi::c_void,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub set_ip_filter: eficall! {fn(
+        *mut Protocol,
+        *mut IpFilter,
+    ) -> crate::base::Status},
+    pub arp: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::IpAddress,
+        *mut crate::base::MacAddress,
+    ) -> crate::base::Status},
+    pub set_parameters: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut u8,
+        *mut u8,
+        *mut crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub set_station_ip: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::IpAddress,
+        *mut crate::base::IpAddress,
+    ) -> crate::base::Status},
+    pub set_packets: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut crate::base::Boolean,
+        *mut Packet,
+        *mut Packet,
+        *mut Packet,
+        *mut Packet,
+        *mut Packet,
+        *mut Packet,
+    ) -> crate::base::Status},
+    pub mode: *mut Mode,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packet_layout() {
+        assert_eq!(core::mem::size_of::<Dhcpv4Packet>(), 296);
+        assert_eq!(core::mem::offset_of!(Dhcpv4Packet, dhcp_magik), 236);
+        assert_eq!(core::mem::size_of::<Dhcpv6Packet>(), 1028);
+        assert_eq!(core::mem::size_of::<Packet>(), 1472);
+        assert_eq!(core::mem::size_of::<IcmpError>(), 504);
+        assert_eq!(core::mem::size_of::<IpFilter>(), 132);
+        assert_eq!(core::mem::offset_of!(Mode, station_ip), 20);
+    }
+}
@@ -0,0 +1,282 @@
+//! Random Number Generator Protocol
+//!
+//! The random number generator protocol provides random bits for use in applications, or
+//! entropy for seeding other random number generators. Firmware can support several algorithms,
+//! each identified by a GUID.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x3152bca5,
+    0xeade,
+    0x433d,
+    0x86,
+    0x2e,
+    &[0xc0, 0x1c, 0xdc, 0x29, 0x1f, 0x44],
+);
+
+pub const ALGORITHM_SP800_90_HASH_256_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xa7af67cb,
+    0x603b,
+    0x4d42,
+    0xba,
+    0x21,
+    &[0x70, 0xbf, 0xb6, 0x29, 0x3f, 0x96],
+);
+
+pub const ALGORITHM_SP800_90_HMAC_256_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xc5149b43,
+    0xae85,
+    0x4f53,
+    0x99,
+    0x82,
+    &[0xb9, 0x43, 0x35, 0xd3, 0xa9, 0xe7],
+);
+
+pub const ALGORITHM_SP800_90_CTR_256_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x44f0de6e,
+    0x4d8c,
+    0x4045,
+    0xa8,
+    0xc7,
+    &[0x4d, 0xd1, 0x68, 0x85, 0x6b, 0x9e],
+);
+
+pub const ALGORITHM_X9_31_3DES_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x63c4785a,
+    0xca34,
+    0x4012,
+    0xa3,
+    0xc8,
+    &[0x0b, 0x6a, 0x32, 0x4f, 0x55, 0x46],
+);
+
+pub const ALGORITHM_X9_31_AES_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xacd03321,
+    0x777e,
+    0x4d3d,
+    0xb1,
+    0xc8,
+    &[0x20, 0xcf, 0xd8, 0x88, 0x20, 0xc9],
+);
+
+pub const ALGORITHM_RAW: crate::base::Guid = crate::base::Guid::from_fields(
+    0xe43176d7,
+    0xb6e8,
+    0x4827,
+    0xb7,
+    0x84,
+    &[0x7f, 0xfd, 0xc4, 0xb6, 0x85, 0x61],
+);
+
+pub type Algorithm = crate::base::Guid;
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_info: eficall! {fn(
+        *mut Protocol,
+        *mut usize,
+        *mut Algorithm,
+    ) -> crate::base::Status},
+    pub get_rng: eficall! {fn(
+        *mut Protocol,
+        *mut Algorithm,
+        usize,
+        *mut u8,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+/// Algorithm Preference Order
+///
+/// This lists the algorithms picked by `preferred_algorithm()`, most preferred first. The
+/// NIST SP800-90 DRBGs come first, followed by the ANSI X9.31 generators. `ALGORITHM_RAW` is not
+/// part of the list, since raw entropy is not meant for direct consumption.
+pub const ALGORITHM_PREFERENCE: [Algorithm; 5] = [
+    ALGORITHM_SP800_90_CTR_256_GUID,
+    ALGORITHM_SP800_90_HMAC_256_GUID,
+    ALGORITHM_SP800_90_HASH_256_GUID,
+    ALGORITHM_X9_31_AES_GUID,
+    ALGORITHM_X9_31_3DES_GUID,
+];
+
+/// Select Preferred Algorithm
+///
+/// This queries the algorithms supported by `rng` via `get_info()` and returns the first entry
+/// of `ALGORITHM_PREFERENCE` that is supported. If none of them is supported (e.g., if only
+/// `ALGORITHM_RAW` is available), or if the query fails, `None` is returned and it is up to the
+/// caller to decide how to proceed.
+///
+/// At most 32 algorithms reported by the firmware are considered, since no allocator is
+/// available. Firmware that reports more than that is treated as if the query failed.
+pub fn preferred_algorithm(rng: &Protocol) -> Option<Algorithm> {
+    let mut list = [crate::base::Guid::from_fields(0, 0, 0, 0, 0, &[0; 6]); 32];
+    let mut size = core::mem::size_of_val(&list);
+
+    let r = (rng.get_info)(
+        rng as *const Protocol as *mut Protocol,
+        &mut size,
+        list.as_mut_ptr(),
+    );
+    if r.is_error() || size > core::mem::size_of_val(&list) {
+        return None;
+    }
+
+    let supported = &list[..size / core::mem::size_of::<Algorithm>()];
+
+    ALGORITHM_PREFERENCE
+        .iter()
+        .find(|v| supported.contains(v))
+        .copied()
+}
+
+/// Generate Random Guid
+///
+/// This retrieves 16 bytes from `rng` and turns them into a version-4 (random) Guid via
+/// `Guid::new_v4_from_bytes()`. The most preferred algorithm of `ALGORITHM_PREFERENCE` is used.
+/// If none of them is supported, the default algorithm of the firmware is used.
+pub fn generate_guid_v4(rng: &Protocol) -> Result<crate::base::Guid, crate::base::Status> {
+    let mut bytes = [0u8; 16];
+    let mut algorithm = preferred_algorithm(rng);
+
+    let r = (rng.get_rng)(
+        rng as *const Protocol as *mut Protocol,
+        algorithm
+            .as_mut()
+            .map_or(core::ptr::null_mut(), |v| v as *mut Algorithm),
+        bytes.len(),
+        bytes.as_mut_ptr(),
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(crate::base::Guid::new_v4_from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    std::thread_local! {
+        static ALGORITHMS: RefCell<Vec<Algorithm>> = const { RefCell::new(Vec::new()) };
+        static SIZES: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn get_info(
+        _this: *mut Protocol,
+        size: *mut usize,
+        list: *mut Algorithm,
+    ) -> crate::base::Status {
+        ALGORITHMS.with(|v| {
+            let v = v.borrow();
+            let n = v.len() * core::mem::size_of::<Algorithm>();
+
+            unsafe {
+                if *size < n {
+                    *size = n;
+                    return crate::base::Status::BUFFER_TOO_SMALL;
+                }
+
+                *size = n;
+                core::ptr::copy_nonoverlapping(v.as_ptr(), list, v.len());
+            }
+            crate::base::Status::SUCCESS
+        })
+    }}
+
+    fn preferred(algorithms: &[Algorithm]) -> Option<Algorithm> {
+        let rng = Protocol {
+            get_info,
+            get_rng: unsafe { crate::mock::stub() },
+        };
+
+        ALGORITHMS.with(|v| *v.borrow_mut() = algorithms.to_vec());
+        preferred_algorithm(&rng)
+    }
+
+    #[test]
+    fn preferred_algorithms() {
+        assert_eq!(preferred(&[]), None);
+        assert_eq!(preferred(&[ALGORITHM_RAW]), None);
+        assert_eq!(
+            preferred(&[ALGORITHM_RAW, ALGORITHM_X9_31_3DES_GUID]),
+            Some(ALGORITHM_X9_31_3DES_GUID),
+        );
+        assert_eq!(
+            preferred(&[
+                ALGORITHM_RAW,
+                ALGORITHM_SP800_90_HASH_256_GUID,
+                ALGORITHM_SP800_90_CTR_256_GUID,
+                ALGORITHM_X9_31_AES_GUID,
+            ]),
+            Some(ALGORITHM_SP800_90_CTR_256_GUID),
+        );
+        assert_eq!(preferred(&[ALGORITHM_RAW; 33]), None);
+    }
+
+    eficall! {fn get_info_oversized(
+        _this: *mut Protocol,
+        size: *mut usize,
+        _list: *mut Algorithm,
+    ) -> crate::base::Status {
+        unsafe { *size *= 2 };
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn preferred_algorithm_oversized() {
+        // Firmware claiming success with a size exceeding the buffer is treated as failure.
+        let rng = Protocol {
+            get_info: get_info_oversized,
+            get_rng: unsafe { crate::mock::stub() },
+        };
+
+        assert_eq!(preferred_algorithm(&rng), None);
+    }
+
+    eficall! {fn get_rng(
+        _this: *mut Protocol,
+        algorithm: *mut Algorithm,
+        size: usize,
+        value: *mut u8,
+    ) -> crate::base::Status {
+        SIZES.with(|v| v.borrow_mut().push(size));
+
+        let fill = match unsafe { algorithm.as_ref() } {
+            None => 0x00,
+            Some(v) if *v == ALGORITHM_SP800_90_CTR_256_GUID => 0xff,
+            Some(_) => return crate::base::Status::UNSUPPORTED,
+        };
+        unsafe { core::ptr::write_bytes(value, fill, size) };
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn guid_v4() {
+        let rng = Protocol { get_info, get_rng };
+
+        // With a supported CSPRNG, it is selected explicitly.
+        ALGORITHMS.with(|v| *v.borrow_mut() = vec![ALGORITHM_RAW, ALGORITHM_SP800_90_CTR_256_GUID]);
+        let guid = generate_guid_v4(&rng).unwrap();
+        assert_eq!(
+            guid.as_fields(),
+            (0xffffffff, 0xffff, 0x4fff, 0xbf, 0xff, &[0xff; 6]),
+        );
+
+        // Otherwise, the firmware default is used.
+        ALGORITHMS.with(|v| *v.borrow_mut() = vec![ALGORITHM_RAW]);
+        let guid = generate_guid_v4(&rng).unwrap();
+        assert_eq!(guid.as_fields(), (0, 0, 0x4000, 0x80, 0, &[0; 6]));
+
+        ALGORITHMS.with(|v| *v.borrow_mut() = vec![ALGORITHM_SP800_90_HASH_256_GUID]);
+        assert_eq!(
+            generate_guid_v4(&rng),
+            Err(crate::base::Status::UNSUPPORTED),
+        );
+
+        assert_eq!(SIZES.with(|v| v.take()), [16; 3]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Serial I/O Protocol
+//!
+//! The serial I/O protocol provides byte-stream access to serial devices. Besides reading and
+//! writing, it allows configuring the line attributes (baud rate, parity, data and stop bits) and
+//! the modem control lines of the device.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xbb25cf6f,
+    0xf1d4,
+    0x11d2,
+    0x9a,
+    0x0c,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0xfd],
+);
+
+pub const TERMINAL_DEVICE_TYPE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x6ad9a60f,
+    0x5815,
+    0x4c7c,
+    0x8a,
+    0x10,
+    &[0x50, 0x53, 0xd2, 0xbf, 0x7a, 0x1b],
+);
+
+pub const REVISION: u32 = 0x00010000u32;
+pub const REVISION1P1: u32 = 0x00010001u32;
+
+pub const DATA_TERMINAL_READY: u32 = 0x00000001u32;
+pub const REQUEST_TO_SEND: u32 = 0x00000002u32;
+pub const CLEAR_TO_SEND: u32 = 0x00000010u32;
+pub const DATA_SET_READY: u32 = 0x00000020u32;
+pub const RING_INDICATE: u32 = 0x00000040u32;
+pub const CARRIER_DETECT: u32 = 0x00000080u32;
+pub const INPUT_BUFFER_EMPTY: u32 = 0x00000100u32;
+pub const OUTPUT_BUFFER_EMPTY: u32 = 0x00000200u32;
+pub const HARDWARE_LOOPBACK_ENABLE: u32 = 0x00001000u32;
+pub const SOFTWARE_LOOPBACK_ENABLE: u32 = 0x00002000u32;
+pub const HARDWARE_FLOW_CONTROL_ENABLE: u32 = 0x00004000u32;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParityType {
+    DefaultParity,
+    NoParity,
+    EvenParity,
+    OddParity,
+    MarkParity,
+    SpaceParity,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StopBits {
+    DefaultStopBits,
+    OneStopBit,
+    OneFiveStopBits,
+    TwoStopBits,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Mode {
+    pub control_mask: u32,
+    pub timeout: u32,
+    pub baud_rate: u64,
+    pub receive_fifo_depth: u32,
+    pub data_bits: u32,
+    // XXX: These carry `ParityType` and `StopBits` values, but are 32-bit integers in the
+    //      mode structure.
+    pub parity: u32,
+    pub stop_bits: u32,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub revision: u32,
+    pub reset: eficall! {fn(
+        *mut Protocol,
+    ) -> crate::base::Status},
+    pub set_attributes: eficall! {fn(
+        *mut Protocol,
+        u64,
+        u32,
+        u32,
+        ParityType,
+        u8,
+        StopBits,
+    ) -> crate::base::Status},
+    pub set_control: eficall! {fn(
+        *mut Protocol,
+        u32,
+    ) -> crate::base::Status},
+    pub get_control: eficall! {fn(
+        *mut Protocol,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub write: eficall! {fn(
+        *mut Protocol,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub read: eficall! {fn(
+        *mut Protocol,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub mode: *mut Mode,
+
+    // Added in REVISION1P1.
+    pub device_type_guid: *const crate::base::Guid,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -0,0 +1,210 @@
+//! Shell Protocol
+//!
+//! The shell protocol is installed by the UEFI shell. It provides applications with access to the
+//! shell environment, like environment variables, aliases, and mappings, as well as file
+//! operations on shell file handles and execution of other shell commands.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x6302d008,
+    0x7f9b,
+    0x4f30,
+    0x87,
+    0xac,
+    &[0x60, 0xc9, 0xfe, 0xf5, 0xda, 0x4e],
+);
+
+pub const MAJOR_VERSION: u32 = 0x00000002u32;
+pub const MINOR_VERSION: u32 = 0x00000002u32;
+
+pub const DEVICE_NAME_USE_COMPONENT_NAME: u32 = 0x00000001u32;
+pub const DEVICE_NAME_USE_DEVICE_PATH: u32 = 0x00000002u32;
+
+pub type FileHandle = *mut core::ffi::c_void;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ListEntry {
+    pub forward_link: *mut ListEntry,
+    pub back_link: *mut ListEntry,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct FileInfo {
+    pub link: ListEntry,
+    pub status: crate::base::Status,
+    pub full_name: *const crate::base::Char16,
+    pub file_name: *const crate::base::Char16,
+    pub handle: FileHandle,
+    pub info: *mut crate::protocols::file::Info,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub execute: eficall! {fn(
+        *mut crate::base::Handle,
+        *mut crate::base::Char16,
+        *mut *mut crate::base::Char16,
+        *mut crate::base::Status,
+    ) -> crate::base::Status},
+    pub get_env: eficall! {fn(
+        *mut crate::base::Char16,
+    ) -> *const crate::base::Char16},
+    pub set_env: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub get_alias: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Boolean,
+    ) -> *const crate::base::Char16},
+    pub set_alias: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+        crate::base::Boolean,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub get_help_text: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_device_path_from_map: eficall! {fn(
+        *mut crate::base::Char16,
+    ) -> *const crate::protocols::device_path::Protocol},
+    pub get_map_from_device_path: eficall! {fn(
+        *mut *mut crate::protocols::device_path::Protocol,
+    ) -> *const crate::base::Char16},
+    pub get_device_path_from_file_path: eficall! {fn(
+        *mut crate::base::Char16,
+    ) -> *mut crate::protocols::device_path::Protocol},
+    pub get_file_path_from_device_path: eficall! {fn(
+        *mut crate::protocols::device_path::Protocol,
+    ) -> *mut crate::base::Char16},
+    pub set_map: eficall! {fn(
+        *mut crate::protocols::device_path::Protocol,
+        *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_cur_dir: eficall! {fn(
+        *mut crate::base::Char16,
+    ) -> *const crate::base::Char16},
+    pub set_cur_dir: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub open_file_list: eficall! {fn(
+        *mut crate::base::Char16,
+        u64,
+        *mut *mut FileInfo,
+    ) -> crate::base::Status},
+    pub free_file_list: eficall! {fn(
+        *mut *mut FileInfo,
+    ) -> crate::base::Status},
+    pub remove_dup_in_file_list: eficall! {fn(
+        *mut *mut FileInfo,
+    ) -> crate::base::Status},
+    pub batch_is_active: eficall! {fn() -> crate::base::Boolean},
+    pub is_root_shell: eficall! {fn() -> crate::base::Boolean},
+    pub enable_page_break: eficall! {fn()},
+    pub disable_page_break: eficall! {fn()},
+    pub get_page_break: eficall! {fn() -> crate::base::Boolean},
+    pub get_device_name: eficall! {fn(
+        crate::base::Handle,
+        u32,
+        *mut crate::base::Char8,
+        *mut *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_file_info: eficall! {fn(
+        FileHandle,
+    ) -> *mut crate::protocols::file::Info},
+    pub set_file_info: eficall! {fn(
+        FileHandle,
+        *mut crate::protocols::file::Info,
+    ) -> crate::base::Status},
+    pub open_file_by_name: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut FileHandle,
+        u64,
+    ) -> crate::base::Status},
+    pub close_file: eficall! {fn(
+        FileHandle,
+    ) -> crate::base::Status},
+    pub create_file: eficall! {fn(
+        *mut crate::base::Char16,
+        u64,
+        *mut FileHandle,
+    ) -> crate::base::Status},
+    pub read_file: eficall! {fn(
+        FileHandle,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub write_file: eficall! {fn(
+        FileHandle,
+        *mut usize,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub delete_file: eficall! {fn(
+        FileHandle,
+    ) -> crate::base::Status},
+    pub delete_file_by_name: eficall! {fn(
+        *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_file_position: eficall! {fn(
+        FileHandle,
+        *mut u64,
+    ) -> crate::base::Status},
+    pub set_file_position: eficall! {fn(
+        FileHandle,
+        u64,
+    ) -> crate::base::Status},
+    pub flush_file: eficall! {fn(
+        FileHandle,
+    ) -> crate::base::Status},
+    pub find_files: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut *mut FileInfo,
+    ) -> crate::base::Status},
+    pub find_files_in_dir: eficall! {fn(
+        FileHandle,
+        *mut *mut FileInfo,
+    ) -> crate::base::Status},
+    pub get_file_size: eficall! {fn(
+        FileHandle,
+        *mut u64,
+    ) -> crate::base::Status},
+    pub open_root: eficall! {fn(
+        *mut crate::protocols::device_path::Protocol,
+        *mut FileHandle,
+    ) -> crate::base::Status},
+    pub open_root_by_handle: eficall! {fn(
+        crate::base::Handle,
+        *mut FileHandle,
+    ) -> crate::base::Status},
+    pub execution_break: crate::base::Event,
+    pub major_version: u32,
+    pub minor_version: u32,
+
+    // Added in version 2.1.
+    pub register_guid_name: eficall! {fn(
+        *mut crate::base::Guid,
+        *mut crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_guid_name: eficall! {fn(
+        *mut crate::base::Guid,
+        *mut *const crate::base::Char16,
+    ) -> crate::base::Status},
+    pub get_guid_from_name: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut crate::base::Guid,
+    ) -> crate::base::Status},
+    pub get_env_ex: eficall! {fn(
+        *mut crate::base::Char16,
+        *mut u32,
+    ) -> *const crate::base::Char16},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
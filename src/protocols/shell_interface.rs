@@ -0,0 +1,46 @@
+//! Shell Interface Protocol
+//!
+//! The shell interface protocol is the legacy interface of the EFI 1.1 shell to the applications
+//! it runs. It is installed on the image handle of each application and provides the command-line
+//! arguments and standard I/O files. Newer shells provide the shell-parameters protocol instead,
+//! but some firmware still only ships the legacy shell.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x47c7b223,
+    0xc42a,
+    0x11d2,
+    0x8e,
+    0x57,
+    &[0x00, 0xa0, 0xc9, 0x69, 0x72, 0x3b],
+);
+
+pub const ARG_NO_ATTRIB: u32 = 0x00000000u32;
+pub const ARG_IS_QUOTED: u32 = 0x00000001u32;
+pub const ARG_PARTIALLY_QUOTED: u32 = 0x00000002u32;
+pub const ARG_FIRST_HALF_QUOTED: u32 = 0x00000004u32;
+pub const ARG_FIRST_CHAR_IS_ESC: u32 = 0x00000008u32;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ArgInfo {
+    pub attributes: u32,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub image_handle: crate::base::Handle,
+    pub info: *mut crate::protocols::loaded_image::Protocol,
+    pub argv: *mut *mut crate::base::Char16,
+    pub argc: usize,
+    pub redir_argv: *mut *mut crate::base::Char16,
+    pub redir_argc: usize,
+    pub std_in: *mut crate::protocols::file::Protocol,
+    pub std_out: *mut crate::protocols::file::Protocol,
+    pub std_err: *mut crate::protocols::file::Protocol,
+    pub arg_info: *mut ArgInfo,
+    pub echo_on: crate::base::Boolean,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
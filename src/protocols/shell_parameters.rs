@@ -0,0 +1,27 @@
+//! Shell Parameters Protocol
+//!
+//! The shell parameters protocol is installed by the UEFI shell on the image handle of each
+//! application it runs. It provides the parsed command-line arguments and the standard I/O file
+//! handles of the application. It supersedes the legacy shell interface protocol.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x752f3136,
+    0x4e16,
+    0x4fdc,
+    0xa2,
+    0x2a,
+    &[0xe5, 0xf4, 0x68, 0x12, 0xf4, 0xca],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub argv: *mut *mut crate::base::Char16,
+    pub argc: usize,
+    pub std_in: crate::protocols::shell::FileHandle,
+    pub std_out: crate::protocols::shell::FileHandle,
+    pub std_err: crate::protocols::shell::FileHandle,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -22,3 +22,379 @@ pub struct Protocol {
         *mut *mut crate::protocols::file::Protocol,
     ) -> crate::base::Status},
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+// Maximum length of a single path component, including the terminating NUL.
+const MAX_COMPONENT: usize = 256;
+
+// Open the file at `path`, relative to the root directory of `fs`. Components are opened one at
+// a time, and intermediate directories are closed again.
+unsafe fn open_path(
+    fs: *mut Protocol,
+    path: &[crate::base::Char16],
+) -> Result<*mut crate::protocols::file::Protocol, crate::base::Status> {
+    let mut file: *mut crate::protocols::file::Protocol = core::ptr::null_mut();
+
+    let r = ((*fs).open_volume)(fs, &mut file);
+    if r.is_error() {
+        return Err(r);
+    }
+
+    let path = crate::string::char16_slice_until_nul(path);
+    for component in path.split(|c| *c == b'\\' as crate::base::Char16) {
+        if component.is_empty() {
+            continue;
+        }
+
+        let mut name = [0 as crate::base::Char16; MAX_COMPONENT];
+        let mut next: *mut crate::protocols::file::Protocol = core::ptr::null_mut();
+
+        let r = if component.len() < MAX_COMPONENT {
+            name[..component.len()].copy_from_slice(component);
+            ((*file).open)(
+                file,
+                &mut next,
+                name.as_mut_ptr(),
+                crate::protocols::file::MODE_READ,
+                0,
+            )
+        } else {
+            crate::base::Status::INVALID_PARAMETER
+        };
+
+        ((*file).close)(file);
+        if r.is_error() {
+            return Err(r);
+        }
+        file = next;
+    }
+
+    Ok(file)
+}
+
+// Query the size of an open regular file via its file information. Directories are rejected
+// with `INVALID_PARAMETER`, since reading them yields directory entries rather than content.
+unsafe fn regular_file_size(
+    file: *mut crate::protocols::file::Protocol,
+) -> Result<u64, crate::base::Status> {
+    // The information is followed by the file name, which is at most `MAX_COMPONENT` characters.
+    let mut info = core::mem::MaybeUninit::<crate::protocols::file::Info<MAX_COMPONENT>>::uninit();
+    let mut size = core::mem::size_of_val(&info);
+    let mut guid = crate::protocols::file::INFO_ID;
+
+    let r = ((*file).get_info)(
+        file,
+        &mut guid,
+        &mut size,
+        info.as_mut_ptr() as *mut core::ffi::c_void,
+    );
+    if r.is_error() {
+        return Err(r);
+    }
+
+    // On success, the firmware filled in the header. Only read the fields needed.
+    let info = info.as_ptr();
+    let attribute = core::ptr::addr_of!((*info).attribute).read();
+    if attribute & crate::protocols::file::DIRECTORY != 0 {
+        Err(crate::base::Status::INVALID_PARAMETER)
+    } else {
+        Ok(core::ptr::addr_of!((*info).file_size).read())
+    }
+}
+
+/// Query File Size
+///
+/// This opens the file at `path` on the file system `fs` and returns its size in bytes, as
+/// reported by its file information. See `read_file()` for the format of `path`. If `path` refers
+/// to a directory, `INVALID_PARAMETER` is returned.
+///
+/// # Safety
+///
+/// `fs` must point to a valid simple-file-system protocol.
+pub unsafe fn file_size(
+    fs: *mut Protocol,
+    path: &[crate::base::Char16],
+) -> Result<u64, crate::base::Status> {
+    let file = open_path(fs, path)?;
+    let r = regular_file_size(file);
+    ((*file).close)(file);
+    r
+}
+
+/// Read Whole File
+///
+/// This opens the file at `path` on the file system `fs` and reads its entire content into
+/// `buffer`. The path is relative to the root directory of the file system, and its components
+/// are separated by backslashes. Empty components are ignored, and the path ends at the first
+/// NUL character, if any. Each component must be shorter than 256 characters.
+///
+/// On success, the size of the file is returned. If `buffer` is smaller than the file,
+/// `BUFFER_TOO_SMALL` is returned without reading the file. Use `file_size()` to query the
+/// required size beforehand. If `path` refers to a directory, `INVALID_PARAMETER` is returned.
+///
+/// # Safety
+///
+/// `fs` must point to a valid simple-file-system protocol.
+pub unsafe fn read_file(
+    fs: *mut Protocol,
+    path: &[crate::base::Char16],
+    buffer: &mut [u8],
+) -> Result<usize, crate::base::Status> {
+    let file = open_path(fs, path)?;
+
+    let r = regular_file_size(file).and_then(|size| {
+        if size > buffer.len() as u64 {
+            return Err(crate::base::Status::BUFFER_TOO_SMALL);
+        }
+
+        let size = size as usize;
+        let mut offset = 0;
+        while offset < size {
+            let mut n = size - offset;
+            let r = ((*file).read)(
+                file,
+                &mut n,
+                buffer[offset..].as_mut_ptr() as *mut core::ffi::c_void,
+            );
+            if r.is_error() {
+                return Err(r);
+            }
+            if n == 0 {
+                // The file shrunk since its size was queried.
+                break;
+            }
+            offset += n;
+        }
+
+        Ok(offset)
+    });
+
+    ((*file).close)(file);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::file;
+    use std::cell::{Cell, RefCell};
+
+    std::thread_local! {
+        static OPEN: Cell<isize> = const { Cell::new(0) };
+        static MODES: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    }
+
+    // A node of the mocked file tree. The file protocol is its first member, so protocol
+    // pointers can be cast back to the node.
+    #[repr(C)]
+    struct Node {
+        file: file::Protocol,
+        name: &'static str,
+        data: &'static [u8],
+        position: Cell<usize>,
+        children: Vec<Node>,
+    }
+
+    eficall! {fn open(
+        this: *mut file::Protocol,
+        new: *mut *mut file::Protocol,
+        name: *mut crate::base::Char16,
+        mode: u64,
+        _attributes: u64,
+    ) -> crate::base::Status {
+        MODES.with(|v| v.borrow_mut().push(mode));
+
+        let node = unsafe { &mut *(this as *mut Node) };
+        let name = unsafe {
+            let len = (0..).position(|i| *name.add(i) == 0).unwrap();
+            String::from_utf16_lossy(core::slice::from_raw_parts(name, len))
+        };
+
+        match node.children.iter_mut().find(|v| v.name == name) {
+            Some(v) => {
+                v.position.set(0);
+                OPEN.with(|v| v.set(v.get() + 1));
+                unsafe { *new = &mut v.file };
+                crate::base::Status::SUCCESS
+            }
+            None => crate::base::Status::NOT_FOUND,
+        }
+    }}
+
+    eficall! {fn close(_this: *mut file::Protocol) -> crate::base::Status {
+        OPEN.with(|v| v.set(v.get() - 1));
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn read(
+        this: *mut file::Protocol,
+        size: *mut usize,
+        buffer: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        let node = unsafe { &*(this as *mut Node) };
+
+        // Return at most 3 bytes per call.
+        let pos = node.position.get();
+        let n = core::cmp::min(core::cmp::min(unsafe { *size }, 3), node.data.len() - pos);
+        unsafe {
+            core::ptr::copy_nonoverlapping(node.data[pos..].as_ptr(), buffer as *mut u8, n);
+            *size = n;
+        }
+        node.position.set(pos + n);
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn get_info(
+        this: *mut file::Protocol,
+        guid: *mut crate::base::Guid,
+        size: *mut usize,
+        buffer: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        if unsafe { *guid } != file::INFO_ID {
+            return crate::base::Status::UNSUPPORTED;
+        }
+
+        let node = unsafe { &*(this as *mut Node) };
+        let needed = core::mem::size_of::<file::Info>() + 2 * (node.name.len() + 1);
+        unsafe {
+            if *size < needed {
+                *size = needed;
+                return crate::base::Status::BUFFER_TOO_SMALL;
+            }
+            *size = needed;
+            let info = buffer as *mut file::Info;
+            (*info).size = needed as u64;
+            (*info).file_size = node.data.len() as u64;
+            (*info).attribute = if node.children.is_empty() {
+                0
+            } else {
+                file::DIRECTORY
+            };
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    fn node(name: &'static str, data: &'static [u8], children: Vec<Node>) -> Node {
+        Node {
+            file: file::Protocol {
+                revision: file::REVISION,
+                open,
+                close,
+                delete: unsafe { crate::mock::stub() },
+                read,
+                write: unsafe { crate::mock::stub() },
+                get_position: unsafe { crate::mock::stub() },
+                set_position: unsafe { crate::mock::stub() },
+                get_info,
+                set_info: unsafe { crate::mock::stub() },
+                flush: unsafe { crate::mock::stub() },
+                open_ex: unsafe { crate::mock::stub() },
+                read_ex: unsafe { crate::mock::stub() },
+                write_ex: unsafe { crate::mock::stub() },
+                flush_ex: unsafe { crate::mock::stub() },
+            },
+            name,
+            data,
+            position: Cell::new(0),
+            children,
+        }
+    }
+
+    #[repr(C)]
+    struct Volume {
+        fs: Protocol,
+        root: Node,
+    }
+
+    eficall! {fn open_volume(
+        this: *mut Protocol,
+        root: *mut *mut file::Protocol,
+    ) -> crate::base::Status {
+        let volume = unsafe { &mut *(this as *mut Volume) };
+        OPEN.with(|v| v.set(v.get() + 1));
+        unsafe { *root = &mut volume.root.file };
+        crate::base::Status::SUCCESS
+    }}
+
+    fn utf16(s: &str) -> Vec<crate::base::Char16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn read_files() {
+        let mut volume = Volume {
+            fs: Protocol {
+                revision: REVISION,
+                open_volume,
+            },
+            root: node(
+                "",
+                b"",
+                vec![
+                    node(
+                        "EFI",
+                        b"",
+                        vec![node(
+                            "BOOT",
+                            b"",
+                            vec![node("kernel", b"0123456789", vec![])],
+                        )],
+                    ),
+                    node("empty", b"", vec![]),
+                ],
+            ),
+        };
+        let fs = &mut volume.fs as *mut Protocol;
+        let mut buffer = [0u8; 16];
+
+        unsafe {
+            assert_eq!(file_size(fs, &utf16("\\EFI\\BOOT\\kernel")), Ok(10));
+            assert_eq!(
+                read_file(fs, &utf16("\\EFI\\BOOT\\kernel"), &mut buffer),
+                Ok(10),
+            );
+            assert_eq!(&buffer[..10], b"0123456789");
+
+            // Empty components and trailing NULs are ignored.
+            buffer = [0u8; 16];
+            assert_eq!(
+                read_file(fs, &utf16("EFI\\\\BOOT\\kernel\0junk"), &mut buffer),
+                Ok(10),
+            );
+            assert_eq!(&buffer[..10], b"0123456789");
+
+            assert_eq!(read_file(fs, &utf16("empty"), &mut buffer), Ok(0));
+            assert_eq!(
+                read_file(fs, &utf16("\\EFI\\BOOT\\kernel"), &mut buffer[..9]),
+                Err(crate::base::Status::BUFFER_TOO_SMALL),
+            );
+            assert_eq!(
+                read_file(fs, &utf16("\\EFI\\missing\\kernel"), &mut buffer),
+                Err(crate::base::Status::NOT_FOUND),
+            );
+
+            // Directories, including the root directory, are rejected.
+            for path in ["\\", "\\EFI", "EFI\\BOOT\\"] {
+                assert_eq!(
+                    file_size(fs, &utf16(path)),
+                    Err(crate::base::Status::INVALID_PARAMETER),
+                );
+                assert_eq!(
+                    read_file(fs, &utf16(path), &mut buffer),
+                    Err(crate::base::Status::INVALID_PARAMETER),
+                );
+            }
+        }
+
+        // All opened files must have been closed again, and all were opened read-only.
+        assert_eq!(OPEN.with(|v| v.get()), 0);
+        MODES.with(|v| {
+            let v = v.borrow();
+            assert!(!v.is_empty());
+            assert!(v.iter().all(|v| *v == file::MODE_READ));
+        });
+    }
+}
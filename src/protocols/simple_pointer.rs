@@ -0,0 +1,52 @@
+//! Simple Pointer Protocol
+//!
+//! The simple pointer protocol provides access to pointer devices that report relative movement,
+//! like mice and track-pads. Movement is reported in counts, which can be converted to
+//! millimeters via the resolution of the device.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x31878c87,
+    0x0b75,
+    0x11d5,
+    0x9a,
+    0x4f,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Mode {
+    pub resolution_x: u64,
+    pub resolution_y: u64,
+    pub resolution_z: u64,
+    pub left_button: crate::base::Boolean,
+    pub right_button: crate::base::Boolean,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct State {
+    pub relative_movement_x: i32,
+    pub relative_movement_y: i32,
+    pub relative_movement_z: i32,
+    pub left_button: crate::base::Boolean,
+    pub right_button: crate::base::Boolean,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub reset: eficall! {fn(
+        *mut Protocol,
+        crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub get_state: eficall! {fn(
+        *mut Protocol,
+        *mut State,
+    ) -> crate::base::Status},
+    pub wait_for_input: crate::base::Event,
+    pub mode: *mut Mode,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
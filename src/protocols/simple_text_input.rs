@@ -13,6 +13,54 @@ pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     &[0x00, 0xa0, 0xc9, 0x69, 0x72, 0x3b],
 );
 
+pub const SCAN_NULL: u16 = 0x0000u16;
+pub const SCAN_UP: u16 = 0x0001u16;
+pub const SCAN_DOWN: u16 = 0x0002u16;
+pub const SCAN_RIGHT: u16 = 0x0003u16;
+pub const SCAN_LEFT: u16 = 0x0004u16;
+pub const SCAN_HOME: u16 = 0x0005u16;
+pub const SCAN_END: u16 = 0x0006u16;
+pub const SCAN_INSERT: u16 = 0x0007u16;
+pub const SCAN_DELETE: u16 = 0x0008u16;
+pub const SCAN_PAGE_UP: u16 = 0x0009u16;
+pub const SCAN_PAGE_DOWN: u16 = 0x000au16;
+pub const SCAN_F1: u16 = 0x000bu16;
+pub const SCAN_F2: u16 = 0x000cu16;
+pub const SCAN_F3: u16 = 0x000du16;
+pub const SCAN_F4: u16 = 0x000eu16;
+pub const SCAN_F5: u16 = 0x000fu16;
+pub const SCAN_F6: u16 = 0x0010u16;
+pub const SCAN_F7: u16 = 0x0011u16;
+pub const SCAN_F8: u16 = 0x0012u16;
+pub const SCAN_F9: u16 = 0x0013u16;
+pub const SCAN_F10: u16 = 0x0014u16;
+pub const SCAN_F11: u16 = 0x0015u16;
+pub const SCAN_F12: u16 = 0x0016u16;
+pub const SCAN_ESC: u16 = 0x0017u16;
+pub const SCAN_PAUSE: u16 = 0x0048u16;
+pub const SCAN_F13: u16 = 0x0068u16;
+pub const SCAN_F14: u16 = 0x0069u16;
+pub const SCAN_F15: u16 = 0x006au16;
+pub const SCAN_F16: u16 = 0x006bu16;
+pub const SCAN_F17: u16 = 0x006cu16;
+pub const SCAN_F18: u16 = 0x006du16;
+pub const SCAN_F19: u16 = 0x006eu16;
+pub const SCAN_F20: u16 = 0x006fu16;
+pub const SCAN_F21: u16 = 0x0070u16;
+pub const SCAN_F22: u16 = 0x0071u16;
+pub const SCAN_F23: u16 = 0x0072u16;
+pub const SCAN_F24: u16 = 0x0073u16;
+pub const SCAN_MUTE: u16 = 0x007fu16;
+pub const SCAN_VOLUME_UP: u16 = 0x0080u16;
+pub const SCAN_VOLUME_DOWN: u16 = 0x0081u16;
+pub const SCAN_BRIGHTNESS_UP: u16 = 0x0100u16;
+pub const SCAN_BRIGHTNESS_DOWN: u16 = 0x0101u16;
+pub const SCAN_SUSPEND: u16 = 0x0102u16;
+pub const SCAN_HIBERNATE: u16 = 0x0103u16;
+pub const SCAN_TOGGLE_DISPLAY: u16 = 0x0104u16;
+pub const SCAN_RECOVERY: u16 = 0x0105u16;
+pub const SCAN_EJECT: u16 = 0x0106u16;
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default)]
 pub struct InputKey {
@@ -32,3 +80,7 @@ pub struct Protocol {
     ) -> crate::base::Status},
     pub wait_for_key: crate::base::Event,
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -73,3 +73,7 @@ pub struct Protocol {
         *mut core::ffi::c_void,
     ) -> crate::base::Status},
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
@@ -13,6 +13,33 @@ pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     &[0x00, 0xa0, 0xc9, 0x69, 0x72, 0x3b],
 );
 
+pub const BLACK: usize = 0x00;
+pub const BLUE: usize = 0x01;
+pub const GREEN: usize = 0x02;
+pub const CYAN: usize = 0x03;
+pub const RED: usize = 0x04;
+pub const MAGENTA: usize = 0x05;
+pub const BROWN: usize = 0x06;
+pub const LIGHTGRAY: usize = 0x07;
+pub const BRIGHT: usize = 0x08;
+pub const DARKGRAY: usize = 0x08;
+pub const LIGHTBLUE: usize = 0x09;
+pub const LIGHTGREEN: usize = 0x0a;
+pub const LIGHTCYAN: usize = 0x0b;
+pub const LIGHTRED: usize = 0x0c;
+pub const LIGHTMAGENTA: usize = 0x0d;
+pub const YELLOW: usize = 0x0e;
+pub const WHITE: usize = 0x0f;
+
+pub const BACKGROUND_BLACK: usize = 0x00;
+pub const BACKGROUND_BLUE: usize = 0x10;
+pub const BACKGROUND_GREEN: usize = 0x20;
+pub const BACKGROUND_CYAN: usize = 0x30;
+pub const BACKGROUND_RED: usize = 0x40;
+pub const BACKGROUND_MAGENTA: usize = 0x50;
+pub const BACKGROUND_BROWN: usize = 0x60;
+pub const BACKGROUND_LIGHTGRAY: usize = 0x70;
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct Mode {
@@ -66,3 +93,35 @@ pub struct Protocol {
     ) -> crate::base::Status},
     pub mode: *mut Mode,
 }
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+/// Pack Text Attribute
+///
+/// This combines a foreground and a background color into an attribute for `set_attribute()`,
+/// like the `EFI_TEXT_ATTR()` macro of the specification. Both colors are given as plain color
+/// values (e.g., `WHITE` and `BLUE`), not as `BACKGROUND_*` constants. The foreground can be any
+/// of the 16 colors, while only the first 8 colors (`BLACK` to `LIGHTGRAY`) are valid as
+/// background. Excess bits of either color are discarded.
+pub const fn text_attr(foreground: usize, background: usize) -> usize {
+    (foreground & 0x0f) | ((background & 0x07) << 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_attributes() {
+        assert_eq!(text_attr(LIGHTGRAY, BLACK), LIGHTGRAY | BACKGROUND_BLACK);
+        assert_eq!(text_attr(WHITE, BLUE), WHITE | BACKGROUND_BLUE);
+        assert_eq!(text_attr(YELLOW, RED), 0x4e);
+        assert_eq!(text_attr(BLACK, LIGHTGRAY), 0x70);
+        assert_eq!(text_attr(BLUE | BRIGHT, CYAN), LIGHTBLUE | BACKGROUND_CYAN);
+
+        // Bright backgrounds are not supported and lose their intensity bit.
+        assert_eq!(text_attr(WHITE, LIGHTRED), WHITE | BACKGROUND_RED);
+    }
+}
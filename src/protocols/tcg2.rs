@@ -0,0 +1,141 @@
+//! TCG2 Protocol
+//!
+//! The TCG2 protocol provides access to a TPM 2.0 device. It allows measuring data into PCRs and
+//! recording the measurements in the event log, retrieving the event log, and submitting raw
+//! commands to the TPM. It is defined in the "TCG EFI Protocol Specification".
+//!
+//! All structures of this protocol are byte-packed, as defined by the specification.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x607f766c,
+    0x7455,
+    0x42be,
+    0x93,
+    0x0b,
+    &[0xe4, 0xd7, 0x6d, 0xb2, 0x72, 0x0f],
+);
+
+pub const BOOT_HASH_ALG_SHA1: u32 = 0x00000001u32;
+pub const BOOT_HASH_ALG_SHA256: u32 = 0x00000002u32;
+pub const BOOT_HASH_ALG_SHA384: u32 = 0x00000004u32;
+pub const BOOT_HASH_ALG_SHA512: u32 = 0x00000008u32;
+pub const BOOT_HASH_ALG_SM3_256: u32 = 0x00000010u32;
+
+pub const EVENT_LOG_FORMAT_TCG_1_2: u32 = 0x00000001u32;
+pub const EVENT_LOG_FORMAT_TCG_2: u32 = 0x00000002u32;
+
+pub const EXTEND_ONLY: u64 = 0x0000000000000001u64;
+pub const PE_COFF_IMAGE: u64 = 0x0000000000000010u64;
+
+pub const EVENT_HEADER_VERSION: u16 = 0x0001u16;
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+}
+
+/// Boot-Service Capability
+///
+/// This is the structure filled in by `get_capability()`. The caller must initialize `size` to
+/// the size of the structure it provides. Firmware only fills in as many members as fit.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct BootServiceCapability {
+    pub size: u8,
+    pub structure_version: Version,
+    pub protocol_version: Version,
+    pub hash_algorithm_bitmap: u32,
+    pub supported_event_logs: u32,
+    pub tpm_present_flag: crate::base::Boolean,
+    pub max_command_size: u16,
+    pub max_response_size: u16,
+    pub manufacturer_id: u32,
+    pub number_of_pcr_banks: u32,
+    pub active_pcr_banks: u32,
+}
+
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct EventHeader {
+    pub header_size: u32,
+    pub header_version: u16,
+    pub pcr_index: u32,
+    pub event_type: u32,
+}
+
+/// Measurement Event
+///
+/// This is the event passed to `hash_log_extend_event()`. `size` covers the entire structure
+/// including the trailing event data. The `N` parameter allows accessing the event data with a
+/// compile-time fixed size. It defaults to `0`, in which case only the header is accessible.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct Event<const N: usize = 0> {
+    pub size: u32,
+    pub header: EventHeader,
+    pub event: [u8; N],
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_capability: eficall! {fn(
+        *mut Protocol,
+        *mut BootServiceCapability,
+    ) -> crate::base::Status},
+    pub get_event_log: eficall! {fn(
+        *mut Protocol,
+        u32,
+        *mut crate::base::PhysicalAddress,
+        *mut crate::base::PhysicalAddress,
+        *mut crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub hash_log_extend_event: eficall! {fn(
+        *mut Protocol,
+        u64,
+        crate::base::PhysicalAddress,
+        u64,
+        *mut Event,
+    ) -> crate::base::Status},
+    pub submit_command: eficall! {fn(
+        *mut Protocol,
+        u32,
+        *mut u8,
+        u32,
+        *mut u8,
+    ) -> crate::base::Status},
+    pub get_active_pcr_banks: eficall! {fn(
+        *mut Protocol,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub set_active_pcr_banks: eficall! {fn(
+        *mut Protocol,
+        u32,
+    ) -> crate::base::Status},
+    pub get_result_of_set_active_pcr_banks: eficall! {fn(
+        *mut Protocol,
+        *mut u32,
+        *mut u32,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+
+    // Verify the TCG2 structures are byte-packed as mandated by the specification.
+    #[test]
+    fn packing() {
+        assert_eq!(size_of::<BootServiceCapability>(), 30);
+        assert_eq!(align_of::<BootServiceCapability>(), 1);
+        assert_eq!(size_of::<EventHeader>(), 14);
+        assert_eq!(size_of::<Event>(), 18);
+        assert_eq!(size_of::<Event<4>>(), 22);
+    }
+}
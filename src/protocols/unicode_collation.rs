@@ -0,0 +1,53 @@
+//! Unicode Collation Protocol
+//!
+//! The unicode collation protocol provides language-aware string operations, like
+//! case-insensitive comparison, pattern matching, and case conversion. It also converts between
+//! UCS-2 strings and FAT 8.3 file names in the OEM character set of the FAT file system.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xa4c751fc,
+    0x23ae,
+    0x4c3e,
+    0x92,
+    0xe9,
+    &[0x49, 0x64, 0xcf, 0x63, 0xf3, 0x49],
+);
+
+#[repr(C)]
+pub struct Protocol {
+    pub stri_coll: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+    ) -> isize},
+    pub metai_match: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char16,
+        *mut crate::base::Char16,
+    ) -> crate::base::Boolean},
+    pub str_lwr: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char16,
+    )},
+    pub str_upr: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char16,
+    )},
+    pub fat_to_str: eficall! {fn(
+        *mut Protocol,
+        usize,
+        *mut crate::base::Char8,
+        *mut crate::base::Char16,
+    )},
+    pub str_to_fat: eficall! {fn(
+        *mut Protocol,
+        *mut crate::base::Char16,
+        usize,
+        *mut crate::base::Char8,
+    ) -> crate::base::Boolean},
+    pub supported_languages: *mut crate::base::Char8,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
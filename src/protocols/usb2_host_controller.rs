@@ -0,0 +1,325 @@
+//! USB2 Host Controller Protocol
+//!
+//! The USB2 host-controller protocol provides software abstractions for USB host controllers. It
+//! is used by USB bus drivers to enumerate devices and perform transfers on the bus.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x3e745226,
+    0x9818,
+    0x45b6,
+    0xa2,
+    0xac,
+    &[0xd7, 0xcd, 0x0e, 0x8b, 0xa2, 0xbc],
+);
+
+pub const SPEED_FULL: u8 = 0x00u8;
+pub const SPEED_LOW: u8 = 0x01u8;
+pub const SPEED_HIGH: u8 = 0x02u8;
+pub const SPEED_SUPER: u8 = 0x03u8;
+
+pub const HC_RESET_GLOBAL: u16 = 0x0001u16;
+pub const HC_RESET_HOST_CONTROLLER: u16 = 0x0002u16;
+pub const HC_RESET_GLOBAL_WITH_DEBUG: u16 = 0x0004u16;
+pub const HC_RESET_HOST_WITH_DEBUG: u16 = 0x0008u16;
+
+pub const MAX_BULK_BUFFER_NUM: usize = 10;
+pub const MAX_ISO_BUFFER_NUM: usize = 7;
+pub const MAX_ISO_BUFFER_NUM1: usize = 2;
+
+pub const NOERROR: u32 = 0x00000000u32;
+pub const ERR_NOTEXECUTE: u32 = 0x00000001u32;
+pub const ERR_STALL: u32 = 0x00000002u32;
+pub const ERR_BUFFER: u32 = 0x00000004u32;
+pub const ERR_BABBLE: u32 = 0x00000008u32;
+pub const ERR_NAK: u32 = 0x00000010u32;
+pub const ERR_CRC: u32 = 0x00000020u32;
+pub const ERR_TIMEOUT: u32 = 0x00000040u32;
+pub const ERR_BITSTUFF: u32 = 0x00000080u32;
+pub const ERR_SYSTEM: u32 = 0x00000100u32;
+
+pub const PORT_STAT_CONNECTION: u16 = 0x0001u16;
+pub const PORT_STAT_ENABLE: u16 = 0x0002u16;
+pub const PORT_STAT_SUSPEND: u16 = 0x0004u16;
+pub const PORT_STAT_OVERCURRENT: u16 = 0x0008u16;
+pub const PORT_STAT_RESET: u16 = 0x0010u16;
+pub const PORT_STAT_POWER: u16 = 0x0100u16;
+pub const PORT_STAT_LOW_SPEED: u16 = 0x0200u16;
+pub const PORT_STAT_HIGH_SPEED: u16 = 0x0400u16;
+pub const PORT_STAT_SUPER_SPEED: u16 = 0x0800u16;
+pub const PORT_STAT_OWNER: u16 = 0x2000u16;
+
+pub const PORT_STAT_C_CONNECTION: u16 = 0x0001u16;
+pub const PORT_STAT_C_ENABLE: u16 = 0x0002u16;
+pub const PORT_STAT_C_SUSPEND: u16 = 0x0004u16;
+pub const PORT_STAT_C_OVERCURRENT: u16 = 0x0008u16;
+pub const PORT_STAT_C_RESET: u16 = 0x0010u16;
+
+pub const DESC_TYPE_DEVICE: u8 = 0x01u8;
+pub const DESC_TYPE_CONFIG: u8 = 0x02u8;
+pub const DESC_TYPE_STRING: u8 = 0x03u8;
+pub const DESC_TYPE_INTERFACE: u8 = 0x04u8;
+pub const DESC_TYPE_ENDPOINT: u8 = 0x05u8;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum HcState {
+    HcStateHalt,
+    HcStateOperational,
+    HcStateSuspend,
+    HcStateMaximum,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum DataDirection {
+    DataIn,
+    DataOut,
+    NoData,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub enum PortFeature {
+    PortEnable = 1,
+    PortSuspend = 2,
+    PortReset = 4,
+    PortPower = 8,
+    PortOwner = 13,
+    PortConnectChange = 16,
+    PortEnableChange = 17,
+    PortSuspendChange = 18,
+    PortOverCurrentChange = 19,
+    PortResetChange = 20,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PortStatus {
+    pub port_status: u16,
+    pub port_change_status: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TransactionTranslator {
+    pub translator_hub_address: u8,
+    pub translator_port_number: u8,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DeviceRequest {
+    pub request_type: u8,
+    pub request: u8,
+    pub value: u16,
+    pub index: u16,
+    pub length: u16,
+}
+
+pub type AsyncTransferCallback = eficall! {fn(
+    *mut core::ffi::c_void,
+    usize,
+    *mut core::ffi::c_void,
+    u32,
+) -> crate::base::Status};
+
+#[repr(C)]
+pub struct Protocol {
+    pub get_capability: eficall! {fn(
+        *mut Protocol,
+        *mut u8,
+        *mut u8,
+        *mut u8,
+    ) -> crate::base::Status},
+    pub reset: eficall! {fn(
+        *mut Protocol,
+        u16,
+    ) -> crate::base::Status},
+    pub get_state: eficall! {fn(
+        *mut Protocol,
+        *mut HcState,
+    ) -> crate::base::Status},
+    pub set_state: eficall! {fn(
+        *mut Protocol,
+        HcState,
+    ) -> crate::base::Status},
+    pub control_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        usize,
+        *mut DeviceRequest,
+        DataDirection,
+        *mut core::ffi::c_void,
+        *mut usize,
+        usize,
+        *mut TransactionTranslator,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub bulk_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        u8,
+        usize,
+        u8,
+        *mut *mut core::ffi::c_void,
+        *mut usize,
+        *mut u8,
+        usize,
+        *mut TransactionTranslator,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub async_interrupt_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        u8,
+        usize,
+        crate::base::Boolean,
+        *mut u8,
+        usize,
+        usize,
+        *mut TransactionTranslator,
+        AsyncTransferCallback,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub sync_interrupt_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        u8,
+        usize,
+        *mut core::ffi::c_void,
+        *mut usize,
+        *mut u8,
+        usize,
+        *mut TransactionTranslator,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub isochronous_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        u8,
+        usize,
+        u8,
+        *mut *mut core::ffi::c_void,
+        usize,
+        *mut TransactionTranslator,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub async_isochronous_transfer: eficall! {fn(
+        *mut Protocol,
+        u8,
+        u8,
+        u8,
+        usize,
+        u8,
+        *mut *mut core::ffi::c_void,
+        usize,
+        *mut TransactionTranslator,
+        AsyncTransferCallback,
+        *mut core::ffi::c_void,
+    ) -> crate::base::Status},
+    pub get_root_hub_port_status: eficall! {fn(
+        *mut Protocol,
+        u8,
+        *mut PortStatus,
+    ) -> crate::base::Status},
+    pub set_root_hub_port_feature: eficall! {fn(
+        *mut Protocol,
+        u8,
+        PortFeature,
+    ) -> crate::base::Status},
+    pub clear_root_hub_port_feature: eficall! {fn(
+        *mut Protocol,
+        u8,
+        PortFeature,
+    ) -> crate::base::Status},
+
+    pub major_revision: u16,
+    pub minor_revision: u16,
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+/// Decode String Descriptor
+///
+/// USB string descriptors consist of a 2-byte header (`bLength` and `bDescriptorType`), followed
+/// by the string as UTF-16LE code units without a terminating zero. This validates the header of
+/// the descriptor and returns the string payload.
+///
+/// The descriptor is passed as array of `Char16`, rather than bytes, so the payload is suitably
+/// aligned. That is, read the raw descriptor into a `Char16` buffer. Its first element then holds
+/// the header, with `bLength` in its low byte and `bDescriptorType` in its high byte.
+///
+/// `None` is returned if the descriptor type is not `DESC_TYPE_STRING`, or if `bLength` is odd,
+/// shorter than the header, or exceeds the provided buffer. Any data past `bLength` is ignored.
+pub fn decode_string_descriptor(desc: &[crate::base::Char16]) -> Option<&[crate::base::Char16]> {
+    let header = desc.first()?.to_le_bytes();
+    let len = header[0] as usize;
+
+    if header[1] != DESC_TYPE_STRING || len < 2 || len & 1 != 0 {
+        return None;
+    }
+
+    desc.get(1..len / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn descriptor(bytes: &[u8]) -> Vec<crate::base::Char16> {
+        bytes
+            .chunks(2)
+            .map(|v| u16::from_le_bytes([v[0], *v.get(1).unwrap_or(&0)]))
+            .collect()
+    }
+
+    #[test]
+    fn string_descriptor() {
+        let raw = descriptor(&[
+            0x0a,
+            DESC_TYPE_STRING,
+            b'D',
+            0,
+            b'i',
+            0,
+            b's',
+            0,
+            b'k',
+            0,
+            0xff,
+            0xff,
+        ]);
+        let product: Vec<crate::base::Char16> = "Disk".encode_utf16().collect();
+        assert_eq!(decode_string_descriptor(&raw), Some(&product[..]));
+
+        // Empty strings consist of the header only.
+        let raw = descriptor(&[0x02, DESC_TYPE_STRING]);
+        assert_eq!(decode_string_descriptor(&raw), Some(&[][..]));
+
+        // Invalid types and lengths are rejected.
+        assert_eq!(decode_string_descriptor(&[]), None);
+        assert_eq!(
+            decode_string_descriptor(&descriptor(&[0x04, DESC_TYPE_DEVICE, 0, 0])),
+            None
+        );
+        assert_eq!(
+            decode_string_descriptor(&descriptor(&[0x03, DESC_TYPE_STRING, 0, 0])),
+            None
+        );
+        assert_eq!(
+            decode_string_descriptor(&descriptor(&[0x01, DESC_TYPE_STRING])),
+            None
+        );
+        assert_eq!(
+            decode_string_descriptor(&descriptor(&[0x06, DESC_TYPE_STRING, 0, 0])),
+            None
+        );
+    }
+}
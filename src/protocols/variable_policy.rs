@@ -0,0 +1,97 @@
+//! Variable Policy Protocol
+//!
+//! The variable policy protocol is an EDK2 extension, which is not part of the UEFI
+//! specification. It allows platform code to register policies that restrict how variables can
+//! be written, and to lock the policy engine once all policies are in place.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x81d1675c,
+    0x86f6,
+    0x48df,
+    0xbd,
+    0x95,
+    &[0x9a, 0x6e, 0x4f, 0x09, 0x25, 0xc3],
+);
+
+pub const PROTOCOL_REVISION: u64 = 0x0000000000010000u64;
+
+pub const ENTRY_REVISION: u32 = 0x00010000u32;
+
+pub const NO_MIN_SIZE: u32 = 0x00000000u32;
+pub const NO_MAX_SIZE: u32 = 0xffffffffu32;
+pub const NO_MUST_ATTR: u32 = 0x00000000u32;
+pub const NO_CANT_ATTR: u32 = 0x00000000u32;
+
+pub const TYPE_NO_LOCK: u8 = 0x00u8;
+pub const TYPE_LOCK_NOW: u8 = 0x01u8;
+pub const TYPE_LOCK_ON_CREATE: u8 = 0x02u8;
+pub const TYPE_LOCK_ON_VAR_STATE: u8 = 0x03u8;
+
+/// Variable Policy Entry
+///
+/// This is the header of a variable policy. It is followed by the lock-policy data (only for
+/// `TYPE_LOCK_ON_VAR_STATE`, see `LockOnVarStatePolicy`), and then by the 0-terminated variable
+/// name, starting at `offset_to_name`. `size` covers the entire entry. The structure is packed, so
+/// the namespace GUID is stored as raw bytes.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug)]
+pub struct Entry {
+    pub version: u32,
+    pub size: u16,
+    pub offset_to_name: u16,
+    pub namespace: [u8; 16],
+    pub min_size: u32,
+    pub max_size: u32,
+    pub attributes_must_have: u32,
+    pub attributes_cant_have: u32,
+    pub lock_policy_type: u8,
+    pub padding: [u8; 3],
+}
+
+/// Lock-On-Variable-State Policy
+///
+/// This is the lock-policy data of `TYPE_LOCK_ON_VAR_STATE` entries. The policy locks its variable
+/// once the variable identified by `namespace` and the following 0-terminated name is set to the
+/// single-byte `value`.
+#[repr(C, packed)]
+#[derive(Copy, Clone, Debug)]
+pub struct LockOnVarStatePolicy {
+    pub namespace: [u8; 16],
+    pub value: u8,
+    pub reserved: u8,
+}
+
+#[repr(C)]
+pub struct Protocol {
+    pub revision: u64,
+    pub disable_variable_policy: eficall! {fn() -> crate::base::Status},
+    pub is_variable_policy_enabled: eficall! {fn(
+        *mut crate::base::Boolean,
+    ) -> crate::base::Status},
+    pub register_variable_policy: eficall! {fn(
+        *const Entry,
+    ) -> crate::base::Status},
+    pub dump_variable_policy: eficall! {fn(
+        *mut u8,
+        *mut u32,
+    ) -> crate::base::Status},
+    pub lock_variable_policy: eficall! {fn() -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn entry_layout() {
+        assert_eq!(size_of::<Entry>(), 44);
+        assert_eq!(align_of::<Entry>(), 1);
+        assert_eq!(size_of::<LockOnVarStatePolicy>(), 18);
+        assert_eq!(align_of::<LockOnVarStatePolicy>(), 1);
+    }
+}
@@ -0,0 +1,38 @@
+//! Watchdog Timer Architectural Protocol
+//!
+//! The watchdog-timer architectural protocol is defined by the PI specification. It is used by
+//! the DXE core to implement `set_watchdog_timer()` of the boot services.
+
+pub const PROTOCOL_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x665e3ff5,
+    0x46cc,
+    0x11d4,
+    0x9a,
+    0x38,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub type Notify = eficall! {fn(
+    u64,
+)};
+
+#[repr(C)]
+pub struct Protocol {
+    pub register_handler: eficall! {fn(
+        *mut Protocol,
+        // XXX: This is optional. Pass `None` to unregister a handler.
+        Option<Notify>,
+    ) -> crate::base::Status},
+    pub set_timer_period: eficall! {fn(
+        *mut Protocol,
+        u64,
+    ) -> crate::base::Status},
+    pub get_timer_period: eficall! {fn(
+        *mut Protocol,
+        *mut u64,
+    ) -> crate::base::Status},
+}
+
+impl crate::protocols::ProtocolGuid for Protocol {
+    const GUID: crate::base::Guid = PROTOCOL_GUID;
+}
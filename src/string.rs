@@ -0,0 +1,240 @@
+//! String Helpers
+//!
+//! UEFI uses NUL-terminated UCS-2 strings (`CHAR16*`) throughout its interfaces. This module
+//! provides helpers to work with them without an allocator. They are not part of the
+//! specification.
+
+/// Length of a NUL-terminated String
+///
+/// This walks the string at `ptr` until it hits a NUL character and returns the number of code
+/// units before it. The terminating NUL is not included.
+///
+/// # Safety
+///
+/// `ptr` must point to a readable, NUL-terminated string of `Char16` code units.
+pub unsafe fn char16_strlen(ptr: *const crate::base::Char16) -> usize {
+    let mut len = 0;
+
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+
+    len
+}
+
+/// Truncate at NUL
+///
+/// This returns the part of `slice` before its first NUL character. If the slice contains no
+/// NUL character, it is returned as a whole.
+pub fn char16_slice_until_nul(slice: &[crate::base::Char16]) -> &[crate::base::Char16] {
+    match slice.iter().position(|c| *c == 0) {
+        Some(len) => &slice[..len],
+        None => slice,
+    }
+}
+
+/// UCS-2 Decoding Error
+///
+/// This is returned by `Char16Decoder` for code units that are not valid UCS-2 characters,
+/// which are the surrogate code units `0xd800` to `0xdfff`. `position` is the index of the code
+/// unit in the decoded slice.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    pub position: usize,
+    pub unit: crate::base::Char16,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "invalid UCS-2 code unit {:#06x} at position {}",
+            self.unit, self.position
+        )
+    }
+}
+
+/// UCS-2 Decoder
+///
+/// This iterator decodes a slice of `Char16` code units into `char`s. UEFI uses UCS-2, rather
+/// than UTF-16, so surrogate pairs are not combined. Instead, every surrogate code unit yields a
+/// `DecodeError`, and decoding continues with the next code unit.
+///
+/// By default, decoding stops at the first NUL character. Use `new_whole()` to decode the entire
+/// slice, including any NUL characters.
+#[derive(Clone, Debug)]
+pub struct Char16Decoder<'a> {
+    units: &'a [crate::base::Char16],
+    position: usize,
+}
+
+impl<'a> Char16Decoder<'a> {
+    /// Create a decoder that stops at the first NUL character.
+    pub fn new(units: &'a [crate::base::Char16]) -> Char16Decoder<'a> {
+        Char16Decoder::new_whole(char16_slice_until_nul(units))
+    }
+
+    /// Create a decoder for the entire slice.
+    pub fn new_whole(units: &'a [crate::base::Char16]) -> Char16Decoder<'a> {
+        Char16Decoder { units, position: 0 }
+    }
+}
+
+impl<'a> Iterator for Char16Decoder<'a> {
+    type Item = Result<char, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let unit = *self.units.get(self.position)?;
+        let position = self.position;
+
+        self.position += 1;
+        Some(core::char::from_u32(unit as u32).ok_or(DecodeError { position, unit }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.units.len() - self.position;
+        (n, Some(n))
+    }
+}
+
+impl<'a> ExactSizeIterator for Char16Decoder<'a> {}
+
+/// UCS-2 Encoding Error
+///
+/// This is returned by `encode_ucs2()` if the input cannot be encoded into the output buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The character at the given byte offset of the input is outside of the Basic Multilingual
+    /// Plane, and thus cannot be represented in UCS-2.
+    Unrepresentable(usize),
+    /// The output buffer cannot hold the encoded string including its terminating NUL.
+    BufferTooSmall,
+}
+
+impl core::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            EncodeError::Unrepresentable(offset) => {
+                write!(
+                    f,
+                    "character at offset {} is not representable in UCS-2",
+                    offset
+                )
+            }
+            EncodeError::BufferTooSmall => f.write_str("output buffer too small"),
+        }
+    }
+}
+
+/// Encode UCS-2 String
+///
+/// This encodes `input` as UCS-2 into `out`, followed by a terminating NUL, and returns the number
+/// of code units written (including the NUL). The result can be passed to functions taking a
+/// `CHAR16*` string, like `output_string()` of the simple-text-output protocol.
+///
+/// Characters outside of the Basic Multilingual Plane cannot be represented in UCS-2 and yield
+/// `EncodeError::Unrepresentable`. If `out` is too small, `EncodeError::BufferTooSmall` is
+/// returned. Note that NUL characters of `input` are copied verbatim, and thus terminate the
+/// string early when read by firmware.
+pub fn encode_ucs2(input: &str, out: &mut [crate::base::Char16]) -> Result<usize, EncodeError> {
+    let mut len = 0;
+
+    for (offset, c) in input.char_indices() {
+        let unit = <u16 as core::convert::TryFrom<u32>>::try_from(c as u32)
+            .map_err(|_| EncodeError::Unrepresentable(offset))?;
+        *out.get_mut(len).ok_or(EncodeError::BufferTooSmall)? = unit;
+        len += 1;
+    }
+
+    *out.get_mut(len).ok_or(EncodeError::BufferTooSmall)? = 0;
+    Ok(len + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nul_terminated() {
+        let s = [0x41, 0x42, 0x00, 0x43, 0x00];
+
+        assert_eq!(unsafe { char16_strlen(s.as_ptr()) }, 2);
+        assert_eq!(unsafe { char16_strlen(s[2..].as_ptr()) }, 0);
+        assert_eq!(unsafe { char16_strlen(s[3..].as_ptr()) }, 1);
+
+        assert_eq!(char16_slice_until_nul(&s), [0x41, 0x42]);
+        assert_eq!(char16_slice_until_nul(&s[3..]), [0x43]);
+        assert_eq!(char16_slice_until_nul(&s[..2]), [0x41, 0x42]);
+        assert_eq!(char16_slice_until_nul(&[]), []);
+    }
+
+    #[test]
+    fn decode() {
+        let s: Vec<u16> = "Grüße, €!\0junk".encode_utf16().collect();
+
+        assert_eq!(
+            Char16Decoder::new(&s).collect::<Result<String, _>>(),
+            Ok("Grüße, €!".to_string()),
+        );
+        assert_eq!(
+            Char16Decoder::new_whole(&s).collect::<Result<String, _>>(),
+            Ok("Grüße, €!\0junk".to_string()),
+        );
+        assert_eq!(Char16Decoder::new(&s).len(), 9);
+        assert_eq!(Char16Decoder::new(&[]).next(), None);
+
+        // Surrogates are not combined, but reported individually.
+        let s: Vec<u16> = "a😀b".encode_utf16().collect();
+        let v: Vec<_> = Char16Decoder::new(&s).collect();
+        assert_eq!(
+            v,
+            [
+                Ok('a'),
+                Err(DecodeError {
+                    position: 1,
+                    unit: 0xd83d,
+                }),
+                Err(DecodeError {
+                    position: 2,
+                    unit: 0xde00,
+                }),
+                Ok('b'),
+            ],
+        );
+        assert_eq!(
+            v[1].unwrap_err().to_string(),
+            "invalid UCS-2 code unit 0xd83d at position 1",
+        );
+    }
+
+    #[test]
+    fn encode() {
+        let mut buf = [0xffffu16; 16];
+
+        assert_eq!(encode_ucs2("Grüße €", &mut buf), Ok(8));
+        assert_eq!(buf[..8], [0x47, 0x72, 0xfc, 0xdf, 0x65, 0x20, 0x20ac, 0x00],);
+        assert_eq!(encode_ucs2("", &mut buf), Ok(1));
+        assert_eq!(buf[0], 0);
+
+        // The terminating NUL must fit as well.
+        assert_eq!(encode_ucs2("abc", &mut buf[..4]), Ok(4));
+        assert_eq!(
+            encode_ucs2("abcd", &mut buf[..4]),
+            Err(EncodeError::BufferTooSmall),
+        );
+        assert_eq!(encode_ucs2("", &mut []), Err(EncodeError::BufferTooSmall));
+
+        // Characters outside of the BMP are rejected.
+        assert_eq!(
+            encode_ucs2("ab😀", &mut buf),
+            Err(EncodeError::Unrepresentable(2)),
+        );
+
+        // Round-trip through the decoder.
+        let n = encode_ucs2("Hello, World!", &mut buf).unwrap();
+        assert_eq!(
+            Char16Decoder::new(&buf[..n]).collect::<Result<String, _>>(),
+            Ok("Hello, World!".to_string()),
+        );
+    }
+}
@@ -22,7 +22,7 @@ pub const TIME_IN_DAYLIGHT: u8 = 0x02u8;
 pub const UNSPECIFIED_TIMEZONE: i16 = 0x07ffi16;
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Time {
     pub year: u16,
     pub month: u8,
@@ -37,6 +37,98 @@ pub struct Time {
     pub pad2: u8,
 }
 
+impl Time {
+    fn is_leap_year(year: i64) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    fn days_in_month(year: i64, month: u8) -> u8 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    // Number of days since 1970-01-01 of the given date in the proleptic Gregorian calendar. See
+    // Howard Hinnant's `days_from_civil()` for the algorithm.
+    fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = month as i64;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146097 + doe - 719468
+    }
+
+    // Inverse of `days_from_civil()`.
+    fn civil_from_days(days: i64) -> (i64, u8, u8) {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+
+        (yoe + era * 400 + (month <= 2) as i64, month, day)
+    }
+
+    /// Normalize to UTC
+    ///
+    /// This converts the time to UTC, using the offset in `timezone`. Following UEFI 2.7 and
+    /// later, local time is `UTC + timezone`, with `timezone` given in minutes. Minutes, hours,
+    /// days, months, and years roll over as needed, taking month lengths and leap years into
+    /// account. The returned time has a `timezone` of 0 and `daylight` cleared, since daylight
+    /// saving does not apply to UTC. All other fields are preserved.
+    ///
+    /// `None` is returned if the timezone is `UNSPECIFIED_TIMEZONE`, if any field is out of the
+    /// range defined by the specification, or if the result is outside of the representable years
+    /// (1900 to 9999).
+    pub fn to_utc(&self) -> Option<Time> {
+        let year = self.year as i64;
+
+        if !(1900..=9999).contains(&year)
+            || !(1..=12).contains(&self.month)
+            || self.day < 1
+            || self.day > Self::days_in_month(year, self.month)
+            || self.hour > 23
+            || self.minute > 59
+            || self.second > 59
+            || self.nanosecond > 999_999_999
+            || !(-1440..=1440).contains(&self.timezone)
+        {
+            return None;
+        }
+
+        let minutes = Self::days_from_civil(year, self.month, self.day) * 1440
+            + self.hour as i64 * 60
+            + self.minute as i64
+            - self.timezone as i64;
+        let (year, month, day) = Self::civil_from_days(minutes.div_euclid(1440));
+        let minutes = minutes.rem_euclid(1440);
+
+        if !(1900..=9999).contains(&year) {
+            return None;
+        }
+
+        Some(Time {
+            year: year as u16,
+            month,
+            day,
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+            timezone: 0,
+            daylight: 0,
+            ..*self
+        })
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct TimeCapabilities {
@@ -63,26 +155,30 @@ pub const VARIABLE_ENHANCED_AUTHENTICATED_ACCESS: u32 = 0x00000080u32;
 
 pub const VARIABLE_AUTHENTICATION_3_CERT_ID_SHA256: u32 = 0x1u32;
 
+// The variable-authentication structures end in flexible arrays. The `N` parameter allows
+// accessing the trailing data with a compile-time fixed size. It defaults to `0`, in which case
+// only the header is accessible.
+
 #[repr(C)]
-#[derive(Debug)]
-pub struct VariableAuthentication3CertId {
+#[derive(Clone, Copy, Debug)]
+pub struct VariableAuthentication3CertId<const N: usize = 0> {
     pub r#type: u8,
     pub id_size: u32,
-    pub id: [u8],
+    pub id: [u8; N],
 }
 
 #[repr(C)]
-#[derive(Debug)]
-pub struct VariableAuthentication {
+#[derive(Clone, Copy, Debug)]
+pub struct VariableAuthentication<const N: usize = 0> {
     pub monotonic_count: u64,
-    pub auth_info: [u8], // WIN_CERTIFICATE_UEFI_ID from PE/COFF
+    pub auth_info: [u8; N], // WIN_CERTIFICATE_UEFI_ID from PE/COFF
 }
 
 #[repr(C)]
-#[derive(Debug)]
-pub struct VariableAuthentication2 {
+#[derive(Clone, Copy, Debug)]
+pub struct VariableAuthentication2<const N: usize = 0> {
     pub timestamp: Time,
-    pub auth_info: [u8], // WIN_CERTIFICATE_UEFI_ID from PE/COFF
+    pub auth_info: [u8; N], // WIN_CERTIFICATE_UEFI_ID from PE/COFF
 }
 
 pub const VARIABLE_AUTHENTICATION_3_TIMESTAMP_TYPE: u32 = 0x1u32;
@@ -98,12 +194,21 @@ pub struct VariableAuthentication3 {
 }
 
 #[repr(C)]
-#[derive(Debug)]
-pub struct VariableAuthentication3Nonce {
+#[derive(Clone, Copy, Debug)]
+pub struct VariableAuthentication3Nonce<const N: usize = 0> {
     pub nonce_size: u32,
-    pub nonce: [u8],
+    pub nonce: [u8; N],
 }
 
+pub const GLOBAL_VARIABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8be4df61,
+    0x93ca,
+    0x11d2,
+    0xaa,
+    0x0d,
+    &[0x00, 0xe0, 0x98, 0x03, 0x2b, 0x8c],
+);
+
 pub const HARDWARE_ERROR_VARIABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     0x414E6BDD,
     0xE47B,
@@ -113,6 +218,102 @@ pub const HARDWARE_ERROR_VARIABLE_GUID: crate::base::Guid = crate::base::Guid::f
     &[0xBB, 0x61, 0x02, 0x0C, 0xF5, 0x16],
 );
 
+//
+// Boot Manager
+//
+// The boot manager stores its boot entries as `BootXXXX` variables (with `XXXX` being a
+// hexadecimal number) in the global-variable namespace. Each entry is a serialized load option.
+// The `BootOrder` variable is an array of `u16` entry numbers, which defines the boot sequence.
+//
+
+pub const LOAD_OPTION_ACTIVE: u32 = 0x00000001u32;
+pub const LOAD_OPTION_FORCE_RECONNECT: u32 = 0x00000002u32;
+pub const LOAD_OPTION_HIDDEN: u32 = 0x00000008u32;
+pub const LOAD_OPTION_CATEGORY: u32 = 0x00001f00u32;
+pub const LOAD_OPTION_CATEGORY_BOOT: u32 = 0x00000000u32;
+pub const LOAD_OPTION_CATEGORY_APP: u32 = 0x00000100u32;
+
+/// Load Option
+///
+/// This is the parsed form of a load option, as stored in `BootXXXX` and similar variables. The
+/// serialized form is a packed structure of the attributes, the length of the device-path list,
+/// the NUL-terminated description, the device-path list, and the optional data.
+///
+/// `description` does not include the terminating NUL. `device_path` is the raw device-path
+/// list, which can contain several device-path instances.
+#[derive(Clone, Copy, Debug)]
+pub struct LoadOption<'a> {
+    pub attributes: u32,
+    pub description: &'a [crate::base::Char16],
+    pub device_path: &'a [u8],
+    pub optional_data: &'a [u8],
+}
+
+impl<'a> LoadOption<'a> {
+    /// Parse a serialized load option.
+    ///
+    /// `None` is returned if the data is truncated, the description lacks its terminating NUL,
+    /// or if `data` is not suitably aligned for the `Char16` description (i.e., 2 bytes).
+    pub fn parse(data: &'a [u8]) -> Option<LoadOption<'a>> {
+        let header = data.get(..6)?;
+        let attributes = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let path_len = u16::from_le_bytes([header[4], header[5]]) as usize;
+
+        let (head, chars, _) = unsafe { data[6..].align_to::<crate::base::Char16>() };
+        if !head.is_empty() {
+            return None;
+        }
+        let description = &chars[..chars.iter().position(|c| *c == 0)?];
+
+        let offset = 6 + 2 * (description.len() + 1);
+        let device_path = data.get(offset..offset.checked_add(path_len)?)?;
+        let optional_data = &data[offset + path_len..];
+
+        Some(LoadOption {
+            attributes,
+            description,
+            device_path,
+            optional_data,
+        })
+    }
+
+    /// Return the size of the serialized load option in bytes.
+    pub fn serialized_size(&self) -> usize {
+        6 + 2 * (self.description.len() + 1) + self.device_path.len() + self.optional_data.len()
+    }
+
+    /// Serialize the load option into `out`.
+    ///
+    /// On success, the number of bytes written is returned. `None` is returned if `out` is too
+    /// small, if the device-path list exceeds the 16-bit length field, or if the description
+    /// contains a NUL character.
+    pub fn serialize(&self, out: &mut [u8]) -> Option<usize> {
+        let path_len =
+            <u16 as core::convert::TryFrom<usize>>::try_from(self.device_path.len()).ok()?;
+        let size = self.serialized_size();
+        let out = out.get_mut(..size)?;
+
+        if self.description.contains(&0) {
+            return None;
+        }
+
+        out[0..4].copy_from_slice(&self.attributes.to_le_bytes());
+        out[4..6].copy_from_slice(&path_len.to_le_bytes());
+
+        let mut offset = 6;
+        for c in self.description.iter().chain(core::iter::once(&0)) {
+            out[offset..offset + 2].copy_from_slice(&c.to_le_bytes());
+            offset += 2;
+        }
+
+        out[offset..offset + self.device_path.len()].copy_from_slice(self.device_path);
+        offset += self.device_path.len();
+        out[offset..].copy_from_slice(self.optional_data);
+
+        Some(size)
+    }
+}
+
 //
 // Virtual Mappings
 //
@@ -130,6 +331,16 @@ pub const OPTIONAL_POINTER: u32 = 0x00000001u32;
 // different possible resets.
 //
 
+/// Reset Type
+///
+/// This selects the kind of reset performed by `reset_system()`. Along with the reset type, the
+/// caller passes a status code describing the reason of the reset, and optional reset data.
+///
+/// The reset data is a null-terminated UCS-2 string describing the reset, which can be followed
+/// by binary data. The size covers the string and the binary data. For `ResetPlatformSpecific`,
+/// the string must be followed by a Guid identifying the platform-specific reset. Since the Guid
+/// directly follows the string, it is not necessarily aligned. For all other types, reset data is
+/// optional and a size of 0 with a null pointer can be passed.
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub enum ResetType {
@@ -198,14 +409,16 @@ pub struct CapsuleResultVariableHeader {
     pub capsule_status: crate::base::Status,
 }
 
+// The capsule file name and target are two consecutive zero-terminated strings, stored as a
+// flexible array. `N` works as for the variable-authentication structures.
 #[repr(C)]
-#[derive(Debug)]
-pub struct CapsuleResultVariableFMP {
+#[derive(Clone, Copy, Debug)]
+pub struct CapsuleResultVariableFMP<const N: usize = 0> {
     pub version: u16,
     pub payload_index: u8,
     pub update_image_index: u8,
     pub update_image_type_id: crate::base::Guid,
-    pub capsule_file_name_and_target: [crate::base::Char16],
+    pub capsule_file_name_and_target: [crate::base::Char16; N],
 }
 
 //
@@ -263,6 +476,14 @@ pub const EVENT_GROUP_READY_TO_BOOT: crate::base::Guid = crate::base::Guid::from
     0xa8,
     &[0xa8, 0xd8, 0xde, 0xe5, 0x0d, 0x2b],
 );
+pub const EVENT_GROUP_AFTER_READY_TO_BOOT: crate::base::Guid = crate::base::Guid::from_fields(
+    0x3a2a00ad,
+    0x98b9,
+    0x4cdf,
+    0xa4,
+    0x78,
+    &[0x70, 0x27, 0x77, 0xf1, 0xc1, 0x0b],
+);
 pub const EVENT_GROUP_RESET_SYSTEM: crate::base::Guid = crate::base::Guid::from_fields(
     0x62da6a56,
     0x13fb,
@@ -322,6 +543,63 @@ pub enum MemoryType {
     PersistentMemory,
 }
 
+impl MemoryType {
+    /// Convert a raw memory type, as found in memory descriptors, into a `MemoryType`. `None` is
+    /// returned for types not defined by the specification (e.g., OEM and OS-loader types).
+    pub fn from_u32(v: u32) -> Option<MemoryType> {
+        Some(match v {
+            0 => MemoryType::ReservedMemoryType,
+            1 => MemoryType::LoaderCode,
+            2 => MemoryType::LoaderData,
+            3 => MemoryType::BootServicesCode,
+            4 => MemoryType::BootServicesData,
+            5 => MemoryType::RuntimeServicesCode,
+            6 => MemoryType::RuntimeServicesData,
+            7 => MemoryType::ConventionalMemory,
+            8 => MemoryType::UnusableMemory,
+            9 => MemoryType::AcpiReclaimMemory,
+            10 => MemoryType::AcpiMemoryNvs,
+            11 => MemoryType::MemoryMappedIO,
+            12 => MemoryType::MemoryMappedIOPortSpace,
+            13 => MemoryType::PalCode,
+            14 => MemoryType::PersistentMemory,
+            _ => return None,
+        })
+    }
+
+    /// Return the name of the memory type, as used in the specification (without `Efi` prefix).
+    pub fn name(&self) -> &'static str {
+        match self {
+            MemoryType::ReservedMemoryType => "ReservedMemoryType",
+            MemoryType::LoaderCode => "LoaderCode",
+            MemoryType::LoaderData => "LoaderData",
+            MemoryType::BootServicesCode => "BootServicesCode",
+            MemoryType::BootServicesData => "BootServicesData",
+            MemoryType::RuntimeServicesCode => "RuntimeServicesCode",
+            MemoryType::RuntimeServicesData => "RuntimeServicesData",
+            MemoryType::ConventionalMemory => "ConventionalMemory",
+            MemoryType::UnusableMemory => "UnusableMemory",
+            MemoryType::AcpiReclaimMemory => "ACPIReclaimMemory",
+            MemoryType::AcpiMemoryNvs => "ACPIMemoryNVS",
+            MemoryType::MemoryMappedIO => "MemoryMappedIO",
+            MemoryType::MemoryMappedIOPortSpace => "MemoryMappedIOPortSpace",
+            MemoryType::PalCode => "PalCode",
+            MemoryType::PersistentMemory => "PersistentMemory",
+        }
+    }
+}
+
+// Memory types in these ranges are reserved for OEM and OS-loader use, respectively. They can
+// appear in memory descriptors, but have no `MemoryType` equivalent. Hence, descriptors store the
+// raw type value.
+pub const MEMORY_TYPE_OEM_RESERVED_MIN: u32 = 0x70000000u32;
+pub const MEMORY_TYPE_OEM_RESERVED_MAX: u32 = 0x7fffffffu32;
+pub const MEMORY_TYPE_OS_RESERVED_MIN: u32 = 0x80000000u32;
+pub const MEMORY_TYPE_OS_RESERVED_MAX: u32 = 0xffffffffu32;
+
+/// Size of a memory page in bytes, as used by the page allocator and memory descriptors.
+pub const PAGE_SIZE: usize = 0x1000usize;
+
 pub const MEMORY_UC: u64 = 0x0000000000000001u64;
 pub const MEMORY_WC: u64 = 0x0000000000000002u64;
 pub const MEMORY_WT: u64 = 0x0000000000000004u64;
@@ -338,7 +616,7 @@ pub const MEMORY_RUNTIME: u64 = 0x8000000000000000u64;
 pub const MEMORY_DESCRIPTOR_VERSION: u32 = 0x00000001u32;
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct MemoryDescriptor {
     pub r#type: u32,
     pub physical_start: crate::base::PhysicalAddress,
@@ -347,6 +625,77 @@ pub struct MemoryDescriptor {
     pub attribute: u64,
 }
 
+// Names of the memory attributes, in the order they are printed.
+const MEMORY_ATTRIBUTE_NAMES: [(u64, &str); 12] = [
+    (MEMORY_UC, "UC"),
+    (MEMORY_WC, "WC"),
+    (MEMORY_WT, "WT"),
+    (MEMORY_WB, "WB"),
+    (MEMORY_UCE, "UCE"),
+    (MEMORY_WP, "WP"),
+    (MEMORY_RP, "RP"),
+    (MEMORY_XP, "XP"),
+    (MEMORY_NV, "NV"),
+    (MEMORY_MORE_RELIABLE, "MORE_RELIABLE"),
+    (MEMORY_RO, "RO"),
+    (MEMORY_RUNTIME, "RUNTIME"),
+];
+
+impl MemoryDescriptor {
+    /// Format Memory Descriptor
+    ///
+    /// This returns a formatter that prints the descriptor in a single line, suitable for
+    /// `memmap`-style diagnostics. It shows the name of the memory type, the physical range
+    /// (with an exclusive end), the number of pages, and the attribute flags:
+    ///
+    /// `ConventionalMemory 0x0000000000100000-0x0000000000200000 256 UC|WB`
+    ///
+    /// Unknown memory types and attribute bits are printed as hexadecimal numbers. A descriptor
+    /// without attributes prints `-` instead.
+    pub fn display(&self) -> impl core::fmt::Display + '_ {
+        struct Display<'a>(&'a MemoryDescriptor);
+
+        impl<'a> core::fmt::Display for Display<'a> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                let d = self.0;
+                let size = d.number_of_pages.wrapping_mul(PAGE_SIZE as u64);
+
+                match MemoryType::from_u32(d.r#type) {
+                    Some(t) => f.write_str(t.name())?,
+                    None => write!(f, "{:#010x}", d.r#type)?,
+                }
+                write!(
+                    f,
+                    " {:#018x}-{:#018x} {} ",
+                    d.physical_start,
+                    d.physical_start.wrapping_add(size),
+                    d.number_of_pages,
+                )?;
+
+                if d.attribute == 0 {
+                    return f.write_str("-");
+                }
+
+                let mut rest = d.attribute;
+                let mut sep = "";
+                for (flag, name) in MEMORY_ATTRIBUTE_NAMES.iter() {
+                    if rest & flag != 0 {
+                        write!(f, "{}{}", sep, name)?;
+                        rest &= !flag;
+                        sep = "|";
+                    }
+                }
+                if rest != 0 {
+                    write!(f, "{}{:#x}", sep, rest)?;
+                }
+                Ok(())
+            }
+        }
+
+        Display(self)
+    }
+}
+
 //
 // Protocol Management
 //
@@ -386,6 +735,27 @@ pub struct OpenProtocolInformationEntry {
     pub open_count: u32,
 }
 
+// Some protocols carry no interface at all. They are installed with a NULL interface pointer and
+// merely mark a handle with a specific property. The driver model uses the following markers.
+
+pub const HOT_PLUG_DEVICE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x220ac432,
+    0x1d43,
+    0x49e5,
+    0xa7,
+    0x4f,
+    &[0x4c, 0x9d, 0xa6, 0x7a, 0xd2, 0x3b],
+);
+pub const BUS_SPECIFIC_DRIVER_OVERRIDE_PROTOCOL_GUID: crate::base::Guid =
+    crate::base::Guid::from_fields(
+        0x3bc1b285,
+        0x8a15,
+        0x4a82,
+        0xaa,
+        0xbf,
+        &[0x4d, 0x7d, 0x13, 0xfb, 0x32, 0x65],
+    );
+
 //
 // Configuration Tables
 //
@@ -400,6 +770,138 @@ pub struct ConfigurationTable {
     pub vendor_table: *mut core::ffi::c_void,
 }
 
+/// Configuration-Table Array
+///
+/// This wraps the array of configuration tables of the system table. It can be iterated, yielding
+/// each entry, and provides a lookup of tables by their vendor GUID.
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigurationTables<'a> {
+    entries: &'a [ConfigurationTable],
+}
+
+impl<'a> ConfigurationTables<'a> {
+    /// Wrap `count` configuration tables starting at `entries`. A null pointer or a count of 0
+    /// yields an empty array.
+    ///
+    /// # Safety
+    ///
+    /// Unless null, `entries` must point to `count` configuration tables, which stay valid and
+    /// unmodified for the lifetime `'a`.
+    pub unsafe fn new(entries: *const ConfigurationTable, count: usize) -> ConfigurationTables<'a> {
+        let entries = if entries.is_null() || count == 0 {
+            &[]
+        } else {
+            core::slice::from_raw_parts(entries, count)
+        };
+
+        ConfigurationTables { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'a, ConfigurationTable> {
+        self.entries.iter()
+    }
+
+    /// Return the table of the first entry with the given vendor GUID, if any.
+    pub fn find(&self, guid: &crate::base::Guid) -> Option<*mut core::ffi::c_void> {
+        self.entries
+            .iter()
+            .find(|v| v.vendor_guid == *guid)
+            .map(|v| v.vendor_table)
+    }
+}
+
+impl<'a> IntoIterator for ConfigurationTables<'a> {
+    type Item = &'a ConfigurationTable;
+    type IntoIter = core::slice::Iter<'a, ConfigurationTable>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<'a> IntoIterator for &ConfigurationTables<'a> {
+    type Item = &'a ConfigurationTable;
+    type IntoIter = core::slice::Iter<'a, ConfigurationTable>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+// Well-known vendor GUIDs of configuration tables defined by other specifications. These tables
+// are not described here, but can be located via `ConfigurationTables::find()`.
+
+pub const ACPI_20_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0x8868e871,
+    0xe4f1,
+    0x11d3,
+    0xbc,
+    0x22,
+    &[0x00, 0x80, 0xc7, 0x3c, 0x88, 0x81],
+);
+
+pub const ACPI_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xeb9d2d30,
+    0x2d88,
+    0x11d3,
+    0x9a,
+    0x16,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub const SAL_SYSTEM_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xeb9d2d32,
+    0x2d88,
+    0x11d3,
+    0x9a,
+    0x16,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub const SMBIOS_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xeb9d2d31,
+    0x2d88,
+    0x11d3,
+    0x9a,
+    0x16,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub const SMBIOS3_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xf2fd1544,
+    0x9794,
+    0x4a2c,
+    0x99,
+    0x2e,
+    &[0xe5, 0xbb, 0xcf, 0x20, 0xe3, 0x94],
+);
+
+pub const MPS_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xeb9d2d2f,
+    0x2d88,
+    0x11d3,
+    0x9a,
+    0x16,
+    &[0x00, 0x90, 0x27, 0x3f, 0xc1, 0x4d],
+);
+
+pub const DTB_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
+    0xb1b621d5,
+    0xf19c,
+    0x41a5,
+    0x83,
+    0x0b,
+    &[0xd9, 0x15, 0x2c, 0x69, 0xaa, 0xe0],
+);
+
 pub const PROPERTIES_TABLE_GUID: crate::base::Guid = crate::base::Guid::from_fields(
     0x880aaca3,
     0x4adc,
@@ -432,16 +934,109 @@ pub const MEMORY_ATTRIBUTES_TABLE_GUID: crate::base::Guid = crate::base::Guid::f
 
 pub const MEMORY_ATTRIBUTES_TABLE_VERSION: u32 = 0x00000001u32;
 
+pub const MEMORY_ATTRIBUTES_FLAGS_RT_FORWARD_CONTROL_FLOW_GUARD: u32 = 0x00000001u32;
+
+/// Memory Attributes Table
+///
+/// This is the header of the memory-attributes table. It is directly followed by
+/// `number_of_entries` memory descriptors, each `descriptor_size` bytes in size. Note that the
+/// descriptor size can exceed the size of `MemoryDescriptor`, so the entries must not be accessed
+/// as a plain array. Use `entries()` to iterate them.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct MemoryAttributesTable {
     pub version: u32,
     pub number_of_entries: u32,
     pub descriptor_size: u32,
-    pub reserved: u32,
-    pub entry: [MemoryDescriptor],
+    pub flags: u32,
+}
+
+impl MemoryAttributesTable {
+    /// Iterate Memory Descriptors
+    ///
+    /// This returns an iterator over the memory descriptors that trail the table header.
+    ///
+    /// # Safety
+    ///
+    /// The table header must be followed by `number_of_entries` memory descriptors of
+    /// `descriptor_size` bytes each, as is the case for tables published by the firmware.
+    pub unsafe fn entries(&self) -> MemoryDescriptors<'_> {
+        let first = (self as *const MemoryAttributesTable).add(1) as *const MemoryDescriptor;
+
+        MemoryDescriptors::new(
+            first,
+            self.number_of_entries as usize,
+            self.descriptor_size as usize,
+        )
+    }
+}
+
+/// Memory Descriptor Iterator
+///
+/// Firmware returns memory descriptors as arrays with a stride of `descriptor_size` bytes, rather
+/// than `size_of::<MemoryDescriptor>()`. This allows extending the descriptor in future revisions
+/// of the specification. This iterator walks such an array and yields each descriptor. It can be
+/// used for the memory map as returned by `get_memory_map()`, as well as the memory-attributes
+/// table.
+///
+/// If `descriptor_size` is smaller than `MemoryDescriptor`, the array is malformed and the
+/// iterator yields no entries.
+#[derive(Clone, Debug)]
+pub struct MemoryDescriptors<'a> {
+    next: *const u8,
+    remaining: usize,
+    descriptor_size: usize,
+    _lifetime: core::marker::PhantomData<&'a MemoryDescriptor>,
+}
+
+impl<'a> MemoryDescriptors<'a> {
+    /// Create an iterator over `count` descriptors starting at `first`.
+    ///
+    /// # Safety
+    ///
+    /// `first` must point to `count` memory descriptors of `descriptor_size` bytes each, which
+    /// are suitably aligned and stay valid and unmodified for the lifetime `'a`.
+    pub unsafe fn new(
+        first: *const MemoryDescriptor,
+        count: usize,
+        descriptor_size: usize,
+    ) -> MemoryDescriptors<'a> {
+        let remaining = if descriptor_size < core::mem::size_of::<MemoryDescriptor>() {
+            0
+        } else {
+            count
+        };
+
+        MemoryDescriptors {
+            next: first as *const u8,
+            remaining,
+            descriptor_size,
+            _lifetime: core::marker::PhantomData,
+        }
+    }
 }
 
+impl<'a> Iterator for MemoryDescriptors<'a> {
+    type Item = &'a MemoryDescriptor;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let v = unsafe { &*(self.next as *const MemoryDescriptor) };
+        self.next = self.next.wrapping_add(self.descriptor_size);
+        self.remaining -= 1;
+        Some(v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for MemoryDescriptors<'a> {}
+
 //
 // Global Tables
 //
@@ -800,7 +1395,7 @@ pub const SYSTEM_TABLE_REVISION_2_31: u32 = (2 << 16) | (31);
 pub const SYSTEM_TABLE_REVISION_2_30: u32 = (2 << 16) | (30);
 pub const SYSTEM_TABLE_REVISION_2_20: u32 = (2 << 16) | (20);
 pub const SYSTEM_TABLE_REVISION_2_10: u32 = (2 << 16) | (10);
-pub const SYSTEM_TABLE_REVISION_2_00: u32 = (2 << 16) | (0);
+pub const SYSTEM_TABLE_REVISION_2_00: u32 = 2 << 16;
 pub const SYSTEM_TABLE_REVISION_1_10: u32 = (1 << 16) | (10);
 pub const SYSTEM_TABLE_REVISION_1_02: u32 = (1 << 16) | (2);
 
@@ -826,3 +1421,1684 @@ pub struct SystemTable {
     pub number_of_table_entries: usize,
     pub configuration_table: *mut ConfigurationTable,
 }
+
+impl SystemTable {
+    /// Access Firmware Vendor
+    ///
+    /// This returns the firmware-vendor string of the system table as slice of UCS-2 code units,
+    /// excluding the terminating zero. If no vendor string is provided, an empty slice is
+    /// returned.
+    ///
+    /// # Safety
+    ///
+    /// The `firmware_vendor` field must either be NULL or point to a zero-terminated UCS-2 string
+    /// that lives at least as long as the system table.
+    pub unsafe fn firmware_vendor_units(&self) -> &[crate::base::Char16] {
+        if self.firmware_vendor.is_null() {
+            return &[];
+        }
+
+        core::slice::from_raw_parts(
+            self.firmware_vendor,
+            crate::string::char16_strlen(self.firmware_vendor),
+        )
+    }
+
+    /// Access Firmware Revision
+    ///
+    /// This returns the vendor-specific firmware revision of the system table.
+    pub fn firmware_revision(&self) -> u32 {
+        self.firmware_revision
+    }
+
+    /// Access Configuration Tables
+    ///
+    /// This returns the configuration-table array of the system table.
+    ///
+    /// # Safety
+    ///
+    /// The `configuration_table` and `number_of_table_entries` fields must describe a valid array,
+    /// as is the case for the system table provided by the firmware. The array must not be
+    /// modified while the returned wrapper is in use (e.g., by `install_configuration_table()`).
+    pub unsafe fn configuration_tables(&self) -> ConfigurationTables<'_> {
+        ConfigurationTables::new(self.configuration_table, self.number_of_table_entries)
+    }
+}
+
+//
+// Boot Service Helpers
+//
+// The boot services follow C calling conventions, including in/out pointers and status codes as
+// return values. The helpers below wrap some of the more awkward call patterns. They do not add
+// any abstraction on top, but merely translate the arguments and return values into rust types.
+//
+
+/// Locate Handle of a Device Path
+///
+/// This invokes `locate_device_path()` of the boot services for the given protocol and device
+/// path. On success, the handle of the device that matched the longest prefix of the device path
+/// (and supports the protocol) is returned, and `device_path` is advanced past the matched prefix.
+/// That is, it then points to the remaining device path that was not consumed.
+///
+/// On error, the status code is returned and `device_path` is left unmodified by the firmware.
+pub fn locate_device_path(
+    bs: &BootServices,
+    protocol: &crate::base::Guid,
+    device_path: &mut *mut crate::protocols::device_path::Protocol,
+) -> Result<crate::base::Handle, crate::base::Status> {
+    let mut handle: crate::base::Handle = core::ptr::null_mut();
+
+    let r = (bs.locate_device_path)(
+        protocol as *const crate::base::Guid as *mut crate::base::Guid,
+        device_path,
+        &mut handle,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(handle)
+    }
+}
+
+/// Locate Protocol by Type
+///
+/// This invokes `locate_protocol()` of the boot services with the GUID of the protocol type `P`.
+/// On success, a pointer to the first protocol instance found is returned. No registration key is
+/// passed, so any instance of the protocol in the system is a candidate.
+pub fn locate_protocol<P: crate::protocols::ProtocolGuid>(
+    bs: &BootServices,
+) -> Result<*mut P, crate::base::Status> {
+    let mut guid = P::GUID;
+    let mut interface: *mut core::ffi::c_void = core::ptr::null_mut();
+
+    let r = (bs.locate_protocol)(&mut guid, core::ptr::null_mut(), &mut interface);
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(interface as *mut P)
+    }
+}
+
+/// Protocols of a Handle
+///
+/// This is the array of protocol GUIDs installed on a handle, as returned by
+/// `protocols_per_handle()`. The array is allocated from pool memory by the firmware, and is
+/// released when this object is dropped.
+pub struct HandleProtocols<'a> {
+    bs: &'a BootServices,
+    buffer: *mut *mut crate::base::Guid,
+    count: usize,
+}
+
+impl<'a> HandleProtocols<'a> {
+    /// Return the array of pointers to the protocol GUIDs, as provided by the firmware.
+    pub fn as_slice(&self) -> &[*mut crate::base::Guid] {
+        if self.buffer.is_null() {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(self.buffer, self.count) }
+        }
+    }
+
+    /// Iterate over copies of the protocol GUIDs.
+    pub fn iter(&self) -> impl Iterator<Item = crate::base::Guid> + '_ {
+        self.as_slice().iter().map(|v| unsafe { **v })
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+}
+
+impl<'a> Drop for HandleProtocols<'a> {
+    fn drop(&mut self) {
+        if !self.buffer.is_null() {
+            (self.bs.free_pool)(self.buffer as *mut core::ffi::c_void);
+        }
+    }
+}
+
+/// Query Protocols of a Handle
+///
+/// This invokes `protocols_per_handle()` of the boot services and returns the protocol GUIDs
+/// installed on `handle`. The GUIDs are owned by the firmware and remain valid as long as the
+/// respective protocols stay installed.
+///
+/// # Safety
+///
+/// `bs` must be the boot-services table of the firmware, or an implementation that behaves alike.
+/// That is, on success it must return an array of `count` valid GUID pointers allocated via
+/// `allocate_pool()`. The protocols must stay installed while the returned object is in use.
+pub unsafe fn protocols_per_handle(
+    bs: &BootServices,
+    handle: crate::base::Handle,
+) -> Result<HandleProtocols<'_>, crate::base::Status> {
+    let mut buffer: *mut *mut crate::base::Guid = core::ptr::null_mut();
+    let mut count: usize = 0;
+
+    let r = (bs.protocols_per_handle)(handle, &mut buffer, &mut count);
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(HandleProtocols { bs, buffer, count })
+    }
+}
+
+/// Copy Memory via Boot Services
+///
+/// This invokes `copy_mem()` of the boot services to copy `len` bytes from `src` to `dest`. Unlike
+/// `core::ptr::copy_nonoverlapping()`, the firmware implementation handles overlapping buffers
+/// correctly, similar to `memmove()`. Use this if you explicitly need the firmware implementation
+/// (e.g., for memory regions that require special access patterns).
+///
+/// # Safety
+///
+/// `src` must be valid for reads, and `dest` valid for writes, of `len` bytes.
+pub unsafe fn boot_copy_mem(
+    bs: &BootServices,
+    dest: *mut core::ffi::c_void,
+    src: *const core::ffi::c_void,
+    len: usize,
+) {
+    (bs.copy_mem)(dest, src as *mut core::ffi::c_void, len)
+}
+
+/// Fill Memory via Boot Services
+///
+/// This invokes `set_mem()` of the boot services to fill `len` bytes at `buffer` with `value`.
+///
+/// # Safety
+///
+/// `buffer` must be valid for writes of `len` bytes.
+pub unsafe fn boot_set_mem(
+    bs: &BootServices,
+    buffer: *mut core::ffi::c_void,
+    len: usize,
+    value: u8,
+) {
+    (bs.set_mem)(buffer, len, value)
+}
+
+/// Create Event in Event Group
+///
+/// This invokes `create_event_ex()` of the boot services to create an `EVT_NOTIFY_SIGNAL` event
+/// in the event group identified by `group` (e.g., `EVENT_GROUP_EXIT_BOOT_SERVICES`). Once any
+/// event of the group is signaled, `notify` is queued at `notify_tpl` with `context` as argument.
+/// The event must be closed via `close_event()` when no longer needed.
+pub fn create_event_in_group(
+    bs: &BootServices,
+    group: &crate::base::Guid,
+    notify_tpl: crate::base::Tpl,
+    notify: EventNotify,
+    context: *mut core::ffi::c_void,
+) -> Result<crate::base::Event, crate::base::Status> {
+    let mut event: crate::base::Event = core::ptr::null_mut();
+
+    let r = (bs.create_event_ex)(
+        EVT_NOTIFY_SIGNAL,
+        notify_tpl,
+        notify,
+        context,
+        group,
+        &mut event,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(event)
+    }
+}
+
+/// Get Next Monotonic Count
+///
+/// This invokes `get_next_monotonic_count()` of the boot services. The returned 64-bit count is
+/// incremented by one on each call. Its upper 32 bits are persisted across resets (see
+/// `get_next_high_monotonic_count()`), so counts are unique across boots as well.
+pub fn get_next_monotonic_count(bs: &BootServices) -> Result<u64, crate::base::Status> {
+    let mut count: u64 = 0;
+
+    let r = (bs.get_next_monotonic_count)(&mut count);
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(count)
+    }
+}
+
+/// Get Next High Monotonic Count
+///
+/// This invokes `get_next_high_mono_count()` of the runtime services. It increments the upper 32
+/// bits of the platform monotonic counter and returns them. The lower 32 bits are not part of the
+/// result, so the value is returned as `u32`.
+pub fn get_next_high_monotonic_count(rs: &RuntimeServices) -> Result<u32, crate::base::Status> {
+    let mut count: u32 = 0;
+
+    let r = (rs.get_next_high_mono_count)(&mut count);
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(count)
+    }
+}
+
+/// Calculate CRC32 via Boot Services
+///
+/// This invokes `calculate_crc32()` of the boot services to compute the CRC32 of `data`, as used
+/// for the `crc32` field of table headers. See `crc32()` for a software implementation that does
+/// not require boot services.
+pub fn calculate_crc32(bs: &BootServices, data: &[u8]) -> Result<u32, crate::base::Status> {
+    let mut crc: u32 = 0;
+
+    let r = (bs.calculate_crc32)(
+        data.as_ptr() as *mut core::ffi::c_void,
+        data.len(),
+        &mut crc,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(crc)
+    }
+}
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut v = i as u32;
+        let mut j = 0;
+
+        while j < 8 {
+            v = if v & 1 != 0 {
+                (v >> 1) ^ 0xedb88320
+            } else {
+                v >> 1
+            };
+            j += 1;
+        }
+
+        table[i] = v;
+        i += 1;
+    }
+
+    table
+}
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// Calculate CRC32
+///
+/// This computes the CRC32 of `data` in software. UEFI uses the CRC32 of IEEE 802.3 (as used by
+/// Ethernet and zlib), so this yields the same result as `calculate_crc32()`. This is useful
+/// before boot services are available, after they were exited, or on the host.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for v in data {
+        crc = CRC32_TABLE[((crc ^ *v as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+
+    !crc
+}
+
+/// Install Protocol Interfaces
+///
+/// This installs each pair of protocol GUID and interface of `protocols` on `handle`, via
+/// `install_protocol_interface()` of the boot services. If `handle` is NULL, a new handle is
+/// created by the first installation. It is a non-variadic replacement of
+/// `install_multiple_protocol_interfaces()`.
+///
+/// Like the latter, this is all-or-nothing. If any installation fails, all interfaces installed
+/// so far are uninstalled again, `handle` is restored to its original value, and the error of the
+/// failed installation is returned.
+pub fn install_protocol_interfaces(
+    bs: &BootServices,
+    handle: &mut crate::base::Handle,
+    protocols: &[(crate::base::Guid, *mut core::ffi::c_void)],
+) -> Result<(), crate::base::Status> {
+    let original = *handle;
+
+    for (i, (guid, interface)) in protocols.iter().enumerate() {
+        let mut guid = *guid;
+
+        let r = (bs.install_protocol_interface)(
+            handle,
+            &mut guid,
+            InterfaceType::NativeInterface,
+            *interface,
+        );
+        if r.is_error() {
+            for (guid, interface) in protocols[..i].iter().rev() {
+                let mut guid = *guid;
+                (bs.uninstall_protocol_interface)(*handle, &mut guid, *interface);
+            }
+            *handle = original;
+            return Err(r);
+        }
+    }
+
+    Ok(())
+}
+
+/// Load Image from Buffer
+///
+/// This invokes `load_image()` of the boot services to load the image contained in `image`, with
+/// `parent` as the parent image. No device path is passed, and the image is not loaded as part of
+/// the boot policy. The firmware copies the image, so `image` can be released afterwards. On
+/// success, the handle of the new image is returned, which can be passed to `start_image()`.
+pub fn load_image_from_buffer(
+    bs: &BootServices,
+    parent: crate::base::Handle,
+    image: &[u8],
+) -> Result<crate::base::Handle, crate::base::Status> {
+    let mut handle: crate::base::Handle = core::ptr::null_mut();
+
+    let r = (bs.load_image)(
+        crate::base::Boolean::FALSE,
+        parent,
+        core::ptr::null_mut(),
+        image.as_ptr() as *mut core::ffi::c_void,
+        image.len(),
+        &mut handle,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(handle)
+    }
+}
+
+/// Boot Option Variable Name
+///
+/// This returns the NUL-terminated name of the `BootXXXX` variable of the boot option with the
+/// given number, using upper-case hexadecimal digits as required by the specification.
+pub fn boot_option_name(number: u16) -> [crate::base::Char16; 9] {
+    let mut name = [0 as crate::base::Char16; 9];
+
+    for (i, c) in "Boot".encode_utf16().enumerate() {
+        name[i] = c;
+    }
+    for i in 0..4 {
+        let digit = (number >> (12 - 4 * i)) & 0xf;
+        name[4 + i] = b"0123456789ABCDEF"[digit as usize] as crate::base::Char16;
+    }
+
+    name
+}
+
+fn get_global_variable<'a>(
+    rs: &RuntimeServices,
+    name: &[crate::base::Char16],
+    buffer: &'a mut [u16],
+) -> Result<&'a mut [u8], crate::base::Status> {
+    let mut name_buf = [0 as crate::base::Char16; 16];
+    let mut guid = GLOBAL_VARIABLE_GUID;
+    let mut size = core::mem::size_of_val(buffer);
+
+    name_buf[..name.len()].copy_from_slice(name);
+
+    let r = (rs.get_variable)(
+        name_buf.as_mut_ptr(),
+        &mut guid,
+        core::ptr::null_mut(),
+        &mut size,
+        buffer.as_mut_ptr() as *mut core::ffi::c_void,
+    );
+    if r.is_error() {
+        return Err(r);
+    }
+
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(
+            buffer.as_mut_ptr() as *mut u8,
+            core::mem::size_of_val(buffer),
+        )
+    };
+    Ok(&mut bytes[..size])
+}
+
+fn global_variable_size(
+    rs: &RuntimeServices,
+    name: &[crate::base::Char16],
+) -> Result<usize, crate::base::Status> {
+    let mut name_buf = [0 as crate::base::Char16; 16];
+    let mut guid = GLOBAL_VARIABLE_GUID;
+    let mut size = 0;
+
+    name_buf[..name.len()].copy_from_slice(name);
+
+    let r = (rs.get_variable)(
+        name_buf.as_mut_ptr(),
+        &mut guid,
+        core::ptr::null_mut(),
+        &mut size,
+        core::ptr::null_mut(),
+    );
+    if r.is_error() && r != crate::base::Status::BUFFER_TOO_SMALL {
+        return Err(r);
+    }
+
+    Ok(size)
+}
+
+fn set_global_variable(
+    rs: &RuntimeServices,
+    name: &[crate::base::Char16],
+    data: &[u8],
+) -> Result<(), crate::base::Status> {
+    let mut name_buf = [0 as crate::base::Char16; 16];
+    let mut guid = GLOBAL_VARIABLE_GUID;
+
+    name_buf[..name.len()].copy_from_slice(name);
+
+    let r = (rs.set_variable)(
+        name_buf.as_mut_ptr(),
+        &mut guid,
+        VARIABLE_NON_VOLATILE | VARIABLE_BOOTSERVICE_ACCESS | VARIABLE_RUNTIME_ACCESS,
+        data.len(),
+        data.as_ptr() as *mut core::ffi::c_void,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(())
+    }
+}
+
+const BOOT_ORDER_NAME: [crate::base::Char16; 10] = [
+    b'B' as crate::base::Char16,
+    b'o' as crate::base::Char16,
+    b'o' as crate::base::Char16,
+    b't' as crate::base::Char16,
+    b'O' as crate::base::Char16,
+    b'r' as crate::base::Char16,
+    b'd' as crate::base::Char16,
+    b'e' as crate::base::Char16,
+    b'r' as crate::base::Char16,
+    0,
+];
+
+/// Read Boot Order
+///
+/// This reads the `BootOrder` variable via the runtime services into `buffer` and returns the
+/// boot-option numbers it contains. If the buffer is too small, `BUFFER_TOO_SMALL` is returned.
+/// If no boot order is set, `NOT_FOUND` is returned.
+pub fn get_boot_order<'a>(
+    rs: &RuntimeServices,
+    buffer: &'a mut [u16],
+) -> Result<&'a [u16], crate::base::Status> {
+    let n = get_global_variable(rs, &BOOT_ORDER_NAME, buffer)?.len() / 2;
+
+    // Variable data is little-endian, which matches all supported targets.
+    Ok(&buffer[..n])
+}
+
+/// Read Boot Option
+///
+/// This reads the `BootXXXX` variable of the boot option with the given number via the runtime
+/// services into `buffer` and parses it as load option. A `u16` buffer is used to guarantee the
+/// alignment required by the description. If the variable cannot be parsed, `VOLUME_CORRUPTED`
+/// is returned.
+pub fn get_boot_option<'a>(
+    rs: &RuntimeServices,
+    number: u16,
+    buffer: &'a mut [u16],
+) -> Result<LoadOption<'a>, crate::base::Status> {
+    let data = get_global_variable(rs, &boot_option_name(number), buffer)?;
+
+    LoadOption::parse(data).ok_or(crate::base::Status::VOLUME_CORRUPTED)
+}
+
+/// Add Boot Option
+///
+/// This writes `option` as the `BootXXXX` variable of the boot option with the given number, and
+/// then inserts the number into `BootOrder` at `position`. A position past the end appends the
+/// number. If the number was already part of the boot order, it is moved to the new position. A
+/// missing `BootOrder` variable is treated as empty.
+///
+/// `scratch` is used to serialize the load option and to edit the boot order. It must be large
+/// enough for both, including the inserted number. Otherwise, `BUFFER_TOO_SMALL` is returned and
+/// no variable is written. Note that the boot option is written before the boot order is updated.
+pub fn add_boot_option(
+    rs: &RuntimeServices,
+    number: u16,
+    option: &LoadOption<'_>,
+    position: usize,
+    scratch: &mut [u16],
+) -> Result<(), crate::base::Status> {
+    // Check that the boot order fits before writing the option, so a failure does not leave an
+    // orphaned boot option behind.
+    let order_len = match global_variable_size(rs, &BOOT_ORDER_NAME) {
+        Ok(v) => v / 2,
+        Err(crate::base::Status::NOT_FOUND) => 0,
+        Err(r) => return Err(r),
+    };
+    if order_len >= scratch.len() {
+        return Err(crate::base::Status::BUFFER_TOO_SMALL);
+    }
+
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(
+            scratch.as_mut_ptr() as *mut u8,
+            core::mem::size_of_val(scratch),
+        )
+    };
+    let size = option
+        .serialize(bytes)
+        .ok_or(crate::base::Status::BUFFER_TOO_SMALL)?;
+    set_global_variable(rs, &boot_option_name(number), &bytes[..size])?;
+
+    let n = match get_boot_order(rs, scratch) {
+        Ok(order) => order.len(),
+        Err(crate::base::Status::NOT_FOUND) => 0,
+        Err(r) => return Err(r),
+    };
+
+    let mut len = 0;
+    for i in 0..n {
+        if scratch[i] != number {
+            scratch[len] = scratch[i];
+            len += 1;
+        }
+    }
+    if len >= scratch.len() {
+        return Err(crate::base::Status::BUFFER_TOO_SMALL);
+    }
+
+    let position = core::cmp::min(position, len);
+    scratch.copy_within(position..len, position + 1);
+    scratch[position] = number;
+
+    let order = &scratch[..len + 1];
+    let bytes = unsafe {
+        core::slice::from_raw_parts(order.as_ptr() as *const u8, core::mem::size_of_val(order))
+    };
+    set_global_variable(rs, &BOOT_ORDER_NAME, bytes)
+}
+
+/// Memory Map
+///
+/// This describes a memory map as returned by `get_memory_map()`, stored in a buffer provided by
+/// the caller. `map_size` is the number of bytes of the buffer used by the memory map, and
+/// `map_key` identifies the current state of the memory map.
+pub struct MemoryMap<'a> {
+    pub buffer: &'a mut [MemoryDescriptor],
+    pub map_size: usize,
+    pub map_key: usize,
+    pub descriptor_size: usize,
+    pub descriptor_version: u32,
+}
+
+impl<'a> MemoryMap<'a> {
+    /// Iterate over the descriptors of the memory map.
+    pub fn descriptors(&self) -> MemoryDescriptors<'_> {
+        let size = core::cmp::min(self.map_size, core::mem::size_of_val(self.buffer));
+        let count = size.checked_div(self.descriptor_size).unwrap_or(0);
+
+        unsafe { MemoryDescriptors::new(self.buffer.as_ptr(), count, self.descriptor_size) }
+    }
+}
+
+fn memory_map_into(
+    bs: &BootServices,
+    buffer: &mut [MemoryDescriptor],
+) -> Result<(usize, usize, usize, u32), crate::base::Status> {
+    let mut map_size = core::mem::size_of_val(buffer);
+    let mut map_key: usize = 0;
+    let mut descriptor_size: usize = 0;
+    let mut descriptor_version: u32 = 0;
+
+    let r = (bs.get_memory_map)(
+        &mut map_size,
+        buffer.as_mut_ptr(),
+        &mut map_key,
+        &mut descriptor_size,
+        &mut descriptor_version,
+    );
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok((map_size, map_key, descriptor_size, descriptor_version))
+    }
+}
+
+/// Query Memory Map
+///
+/// This invokes `get_memory_map()` of the boot services with `buffer` as storage. If the buffer
+/// is too small, `BUFFER_TOO_SMALL` is returned. Note that allocating a larger buffer might
+/// itself grow the memory map, so some slack should be added.
+pub fn get_memory_map<'a>(
+    bs: &BootServices,
+    buffer: &'a mut [MemoryDescriptor],
+) -> Result<MemoryMap<'a>, crate::base::Status> {
+    let (map_size, map_key, descriptor_size, descriptor_version) = memory_map_into(bs, buffer)?;
+
+    Ok(MemoryMap {
+        buffer,
+        map_size,
+        map_key,
+        descriptor_size,
+        descriptor_version,
+    })
+}
+
+/// Exit Boot Services
+///
+/// This fetches the memory map into `buffer` and invokes `exit_boot_services()` with its key. If
+/// the memory map changed in between, the firmware rejects the key with `INVALID_PARAMETER`. In
+/// that case the memory map is fetched again and the exit is retried once, as mandated by the
+/// specification. Any other error, or a failure of the retry, is returned.
+///
+/// On success, the final memory map is returned. No boot services must be used afterwards, so
+/// `buffer` must be allocated by the caller beforehand.
+pub fn exit_boot_services<'a>(
+    bs: &BootServices,
+    image: crate::base::Handle,
+    buffer: &'a mut [MemoryDescriptor],
+) -> Result<MemoryMap<'a>, crate::base::Status> {
+    let mut map = memory_map_into(bs, buffer)?;
+
+    let mut r = (bs.exit_boot_services)(image, map.1);
+    if r == crate::base::Status::INVALID_PARAMETER {
+        map = memory_map_into(bs, buffer)?;
+        r = (bs.exit_boot_services)(image, map.1);
+    }
+    if r.is_error() {
+        return Err(r);
+    }
+
+    Ok(MemoryMap {
+        buffer,
+        map_size: map.0,
+        map_key: map.1,
+        descriptor_size: map.2,
+        descriptor_version: map.3,
+    })
+}
+
+/// Stall Execution
+///
+/// This invokes `stall()` of the boot services to busy-wait for at least `microseconds`
+/// microseconds.
+pub fn stall(bs: &BootServices, microseconds: usize) -> Result<(), crate::base::Status> {
+    let r = (bs.stall)(microseconds);
+    if r.is_error() {
+        Err(r)
+    } else {
+        Ok(())
+    }
+}
+
+/// Stall Execution for a Duration
+///
+/// This is like `stall()`, but takes a `Duration`. Sub-microsecond fractions are truncated, and
+/// durations that exceed the range of `usize` in microseconds saturate.
+pub fn stall_duration(
+    bs: &BootServices,
+    duration: core::time::Duration,
+) -> Result<(), crate::base::Status> {
+    let us = duration.as_micros();
+
+    stall(
+        bs,
+        if us > usize::MAX as u128 {
+            usize::MAX
+        } else {
+            us as usize
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::{graphics_output, simple_text_output};
+    use std::cell::RefCell;
+
+    // Static ABI Assertions
+    //
+    // The system tables consist of a table header followed by pointer-sized entries only, except
+    // for the 32-bit firmware revision, which is padded to pointer-size. Verify this at
+    // compile-time. Being part of the tests, this only covers the target of test builds, which is
+    // usually the host.
+    const _: () = {
+        use core::mem::{align_of, size_of};
+
+        assert!(size_of::<TableHeader>() == 24);
+        assert!(size_of::<Time>() == 16);
+        assert!(size_of::<MemoryDescriptor>() == 40);
+        assert!(size_of::<ConfigurationTable>() == 24);
+        assert!(align_of::<ConfigurationTable>() == 8);
+        assert!(size_of::<RuntimeServices>() == 24 + 14 * size_of::<usize>());
+        assert!(size_of::<BootServices>() == 24 + 44 * size_of::<usize>());
+        assert!(size_of::<SystemTable>() == 24 + 12 * size_of::<usize>());
+    };
+
+    std::thread_local! {
+        static STALLS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+        static COUNT: RefCell<u64> = const { RefCell::new(0) };
+    }
+
+    std::thread_local! {
+        static INSTALLED: RefCell<Vec<(usize, crate::base::Guid, usize)>> =
+            const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn install_protocol_interface_tracked(
+        handle: *mut crate::base::Handle,
+        guid: *mut crate::base::Guid,
+        interface_type: InterfaceType,
+        interface: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        if !matches!(interface_type, InterfaceType::NativeInterface) {
+            return crate::base::Status::INVALID_PARAMETER;
+        }
+
+        let guid = unsafe { *guid };
+        if guid == crate::protocols::rng::PROTOCOL_GUID {
+            return crate::base::Status::OUT_OF_RESOURCES;
+        }
+
+        let handle = unsafe {
+            if (*handle).is_null() {
+                *handle = 0x10 as crate::base::Handle;
+            }
+            *handle as usize
+        };
+        INSTALLED.with(|v| v.borrow_mut().push((handle, guid, interface as usize)));
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn uninstall_protocol_interface_tracked(
+        handle: crate::base::Handle,
+        guid: *mut crate::base::Guid,
+        interface: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        let entry = (handle as usize, unsafe { *guid }, interface as usize);
+        INSTALLED.with(|v| {
+            let mut v = v.borrow_mut();
+            match v.iter().position(|e| *e == entry) {
+                Some(i) => {
+                    v.remove(i);
+                    crate::base::Status::SUCCESS
+                }
+                None => crate::base::Status::NOT_FOUND,
+            }
+        })
+    }}
+
+    #[test]
+    fn event_constants() {
+        assert_eq!(TPL_APPLICATION, 4);
+        assert_eq!(TPL_CALLBACK, 8);
+        assert_eq!(TPL_NOTIFY, 16);
+        assert_eq!(TPL_HIGH_LEVEL, 31);
+
+        assert_eq!(EVT_TIMER, 0x80000000);
+        assert_eq!(EVT_RUNTIME, 0x40000000);
+        assert_eq!(EVT_NOTIFY_WAIT, 0x00000100);
+        assert_eq!(EVT_NOTIFY_SIGNAL, 0x00000200);
+        assert_eq!(EVT_SIGNAL_EXIT_BOOT_SERVICES, 0x00000201);
+        assert_eq!(EVT_SIGNAL_VIRTUAL_ADDRESS_CHANGE, 0x60000202);
+    }
+
+    #[test]
+    fn install_interfaces() {
+        let mut bs = crate::mock::boot_services();
+        bs.install_protocol_interface = install_protocol_interface_tracked;
+        bs.uninstall_protocol_interface = uninstall_protocol_interface_tracked;
+
+        let gop = 0x100 as *mut core::ffi::c_void;
+        let sto = 0x200 as *mut core::ffi::c_void;
+        let rng = 0x300 as *mut core::ffi::c_void;
+
+        // A failure in the middle rolls back all previous installations.
+        let mut handle: crate::base::Handle = core::ptr::null_mut();
+        assert_eq!(
+            install_protocol_interfaces(
+                &bs,
+                &mut handle,
+                &[
+                    (graphics_output::PROTOCOL_GUID, gop),
+                    (simple_text_output::PROTOCOL_GUID, sto),
+                    (crate::protocols::rng::PROTOCOL_GUID, rng),
+                ],
+            ),
+            Err(crate::base::Status::OUT_OF_RESOURCES),
+        );
+        assert!(handle.is_null());
+        assert!(INSTALLED.with(|v| v.borrow().is_empty()));
+
+        assert_eq!(
+            install_protocol_interfaces(
+                &bs,
+                &mut handle,
+                &[
+                    (graphics_output::PROTOCOL_GUID, gop),
+                    (simple_text_output::PROTOCOL_GUID, sto),
+                ],
+            ),
+            Ok(()),
+        );
+        assert_eq!(handle as usize, 0x10);
+        assert_eq!(
+            INSTALLED.with(|v| v.borrow().clone()),
+            [
+                (0x10, graphics_output::PROTOCOL_GUID, gop as usize),
+                (0x10, simple_text_output::PROTOCOL_GUID, sto as usize),
+            ],
+        );
+
+        INSTALLED.with(|v| v.borrow_mut().clear());
+    }
+
+    struct Variable {
+        name: Vec<u16>,
+        guid: crate::base::Guid,
+        attributes: u32,
+        value: Vec<u8>,
+    }
+
+    std::thread_local! {
+        static VARIABLES: RefCell<Vec<Variable>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn variable_name(name: *mut crate::base::Char16) -> Vec<u16> {
+        let len = (0..).position(|i| unsafe { *name.add(i) } == 0).unwrap();
+        unsafe { core::slice::from_raw_parts(name, len) }.to_vec()
+    }
+
+    eficall! {fn get_variable_stored(
+        name: *mut crate::base::Char16,
+        guid: *mut crate::base::Guid,
+        _attributes: *mut u32,
+        size: *mut usize,
+        data: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        let name = variable_name(name);
+        let guid = unsafe { *guid };
+        VARIABLES.with(|v| match v.borrow().iter().find(|v| v.name == name && v.guid == guid) {
+            None => crate::base::Status::NOT_FOUND,
+            Some(Variable { value, .. }) => unsafe {
+                if *size < value.len() {
+                    *size = value.len();
+                    return crate::base::Status::BUFFER_TOO_SMALL;
+                }
+                *size = value.len();
+                core::ptr::copy_nonoverlapping(value.as_ptr(), data as *mut u8, value.len());
+                crate::base::Status::SUCCESS
+            },
+        })
+    }}
+
+    eficall! {fn set_variable_stored(
+        name: *mut crate::base::Char16,
+        guid: *mut crate::base::Guid,
+        attributes: u32,
+        size: usize,
+        data: *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        let name = variable_name(name);
+        let guid = unsafe { *guid };
+        let value = unsafe { core::slice::from_raw_parts(data as *const u8, size) }.to_vec();
+        VARIABLES.with(|v| {
+            let mut v = v.borrow_mut();
+            v.retain(|v| v.name != name || v.guid != guid);
+            v.push(Variable {
+                name,
+                guid,
+                attributes,
+                value,
+            });
+        });
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn load_options() {
+        let description: Vec<u16> = "Linux".encode_utf16().collect();
+        let path = [0x7f, 0xff, 0x04, 0x00];
+        let option = LoadOption {
+            attributes: LOAD_OPTION_ACTIVE,
+            description: &description,
+            device_path: &path,
+            optional_data: b"quiet",
+        };
+
+        let mut buffer = [0u16; 32];
+        let bytes = unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, 64) };
+        assert_eq!(option.serialized_size(), 6 + 12 + 4 + 5);
+        assert_eq!(option.serialize(&mut bytes[..26]), None);
+        assert_eq!(option.serialize(bytes), Some(27));
+        assert_eq!(bytes[..8], [0x01, 0x00, 0x00, 0x00, 0x04, 0x00, b'L', 0x00]);
+
+        let parsed = LoadOption::parse(&bytes[..27]).unwrap();
+        assert_eq!(parsed.attributes, LOAD_OPTION_ACTIVE);
+        assert_eq!(parsed.description, &description[..]);
+        assert_eq!(parsed.device_path, path);
+        assert_eq!(parsed.optional_data, b"quiet");
+
+        // Truncated and misaligned data is rejected.
+        assert!(LoadOption::parse(&bytes[..21]).is_none());
+        assert!(LoadOption::parse(&bytes[..10]).is_none());
+        assert!(LoadOption::parse(&bytes[1..28]).is_none());
+
+        assert_eq!(
+            boot_option_name(0x00af),
+            [0x42, 0x6f, 0x6f, 0x74, 0x30, 0x30, 0x41, 0x46, 0x00],
+        );
+    }
+
+    #[test]
+    fn boot_options() {
+        let mut rs = crate::mock::runtime_services();
+        rs.get_variable = get_variable_stored;
+        rs.set_variable = set_variable_stored;
+
+        let description: Vec<u16> = "Linux".encode_utf16().collect();
+        let path = [0x7f, 0xff, 0x04, 0x00];
+        let option = LoadOption {
+            attributes: LOAD_OPTION_ACTIVE,
+            description: &description,
+            device_path: &path,
+            optional_data: &[],
+        };
+        let mut buffer = [0u16; 32];
+
+        assert_eq!(
+            get_boot_order(&rs, &mut buffer),
+            Err(crate::base::Status::NOT_FOUND),
+        );
+
+        add_boot_option(&rs, 0x0003, &option, 0, &mut buffer).unwrap();
+        add_boot_option(&rs, 0x0001, &option, 0, &mut buffer).unwrap();
+        add_boot_option(&rs, 0x0002, &option, 9, &mut buffer).unwrap();
+        assert_eq!(get_boot_order(&rs, &mut buffer).unwrap(), [1, 3, 2]);
+
+        // Existing entries are moved.
+        add_boot_option(&rs, 0x0002, &option, 0, &mut buffer).unwrap();
+        assert_eq!(get_boot_order(&rs, &mut buffer).unwrap(), [2, 1, 3]);
+
+        let parsed = get_boot_option(&rs, 0x0002, &mut buffer).unwrap();
+        assert_eq!(parsed.description, &description[..]);
+        assert_eq!(parsed.device_path, path);
+        assert_eq!(
+            get_boot_option(&rs, 0x0004, &mut buffer).err(),
+            Some(crate::base::Status::NOT_FOUND),
+        );
+        assert_eq!(
+            add_boot_option(&rs, 0x0004, &option, 0, &mut buffer[..3]),
+            Err(crate::base::Status::BUFFER_TOO_SMALL),
+        );
+
+        // BootOrder plus Boot0001 to Boot0003, all global and persistent.
+        let variables = VARIABLES.with(|v| v.take());
+        assert_eq!(variables.len(), 4);
+        assert!(variables.iter().all(|v| {
+            v.guid == GLOBAL_VARIABLE_GUID
+                && v.attributes
+                    == VARIABLE_NON_VOLATILE | VARIABLE_BOOTSERVICE_ACCESS | VARIABLE_RUNTIME_ACCESS
+        }));
+    }
+
+    #[test]
+    fn boot_option_order_too_large() {
+        let mut rs = crate::mock::runtime_services();
+        rs.get_variable = get_variable_stored;
+        rs.set_variable = set_variable_stored;
+
+        let description: Vec<u16> = "EFI".encode_utf16().collect();
+        let option = LoadOption {
+            attributes: LOAD_OPTION_ACTIVE,
+            description: &description,
+            device_path: &[0x7f, 0xff, 0x04, 0x00],
+            optional_data: &[],
+        };
+        let order: Vec<u8> = (0u16..16).flat_map(|v| v.to_le_bytes()).collect();
+        VARIABLES.with(|v| {
+            v.borrow_mut().push(Variable {
+                name: BOOT_ORDER_NAME[..9].to_vec(),
+                guid: GLOBAL_VARIABLE_GUID,
+                attributes: VARIABLE_NON_VOLATILE
+                    | VARIABLE_BOOTSERVICE_ACCESS
+                    | VARIABLE_RUNTIME_ACCESS,
+                value: order,
+            })
+        });
+
+        // The option fits, but the extended boot order does not. Nothing must be written.
+        let mut buffer = [0u16; 16];
+        assert_eq!(
+            add_boot_option(&rs, 0x0020, &option, 0, &mut buffer),
+            Err(crate::base::Status::BUFFER_TOO_SMALL),
+        );
+        assert_eq!(VARIABLES.with(|v| v.borrow().len()), 1);
+
+        let mut buffer = [0u16; 17];
+        add_boot_option(&rs, 0x0020, &option, 0, &mut buffer).unwrap();
+        assert_eq!(get_boot_order(&rs, &mut buffer).unwrap().len(), 17);
+        assert_eq!(VARIABLES.with(|v| v.borrow().len()), 2);
+
+        VARIABLES.with(|v| v.borrow_mut().clear());
+    }
+
+    #[test]
+    fn memory_descriptor_display() {
+        let mut desc = MemoryDescriptor {
+            r#type: MemoryType::ConventionalMemory as u32,
+            physical_start: 0x100000,
+            virtual_start: 0,
+            number_of_pages: 256,
+            attribute: MEMORY_UC | MEMORY_WB | MEMORY_RUNTIME,
+        };
+        assert_eq!(
+            desc.display().to_string(),
+            "ConventionalMemory 0x0000000000100000-0x0000000000200000 256 UC|WB|RUNTIME",
+        );
+
+        desc.r#type = 0x80000001;
+        desc.number_of_pages = 1;
+        desc.attribute = MEMORY_XP | 0x0000000000100000;
+        assert_eq!(
+            desc.display().to_string(),
+            "0x80000001 0x0000000000100000-0x0000000000101000 1 XP|0x100000",
+        );
+
+        desc.r#type = MemoryType::AcpiMemoryNvs as u32;
+        desc.attribute = 0;
+        assert_eq!(
+            desc.display().to_string(),
+            "ACPIMemoryNVS 0x0000000000100000-0x0000000000101000 1 -",
+        );
+    }
+
+    static IMAGE: [u8; 4] = [b'M', b'Z', 0x90, 0x00];
+
+    #[derive(Debug, PartialEq)]
+    struct Load {
+        boot_policy: bool,
+        parent: usize,
+        device_path: usize,
+        source: usize,
+        source_size: usize,
+    }
+
+    std::thread_local! {
+        static LOADS: RefCell<Vec<Load>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn load_image_checked(
+        boot_policy: crate::base::Boolean,
+        parent: crate::base::Handle,
+        device_path: *mut crate::protocols::device_path::Protocol,
+        source: *mut core::ffi::c_void,
+        source_size: usize,
+        handle: *mut crate::base::Handle,
+    ) -> crate::base::Status {
+        LOADS.with(|v| {
+            v.borrow_mut().push(Load {
+                boot_policy: boot_policy.into(),
+                parent: parent as usize,
+                device_path: device_path as usize,
+                source: source as usize,
+                source_size,
+            })
+        });
+
+        if source_size == 0 {
+            return crate::base::Status::LOAD_ERROR;
+        }
+
+        unsafe { *handle = 0x20 as crate::base::Handle };
+        crate::base::Status::SUCCESS
+    }}
+
+    #[test]
+    fn load_image_buffer() {
+        let mut bs = crate::mock::boot_services();
+        bs.load_image = load_image_checked;
+        let parent = 0x10 as crate::base::Handle;
+
+        assert_eq!(
+            load_image_from_buffer(&bs, parent, &IMAGE),
+            Ok(0x20 as crate::base::Handle),
+        );
+        assert_eq!(
+            load_image_from_buffer(&bs, parent, &[]),
+            Err(crate::base::Status::LOAD_ERROR),
+        );
+
+        let loads = LOADS.with(|v| v.take());
+        assert_eq!(loads.len(), 2);
+        assert_eq!(
+            loads[0],
+            Load {
+                boot_policy: false,
+                parent: 0x10,
+                device_path: 0,
+                source: IMAGE.as_ptr() as usize,
+                source_size: IMAGE.len(),
+            },
+        );
+        assert!(!loads[1].boot_policy);
+        assert_eq!(loads[1].parent, 0x10);
+        assert_eq!(loads[1].device_path, 0);
+        assert_eq!(loads[1].source_size, 0);
+    }
+
+    std::thread_local! {
+        static MAP_KEY: RefCell<usize> = const { RefCell::new(0) };
+        static EXITS: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn get_memory_map_keyed(
+        map_size: *mut usize,
+        map: *mut MemoryDescriptor,
+        map_key: *mut usize,
+        descriptor_size: *mut usize,
+        descriptor_version: *mut u32,
+    ) -> crate::base::Status {
+        // Report descriptors with 8 bytes of padding each, as firmware is free to do.
+        let stride = core::mem::size_of::<MemoryDescriptor>() + 8;
+        let n = 3;
+
+        unsafe {
+            *descriptor_size = stride;
+            *descriptor_version = MEMORY_DESCRIPTOR_VERSION;
+            if *map_size < n * stride {
+                *map_size = n * stride;
+                return crate::base::Status::BUFFER_TOO_SMALL;
+            }
+            *map_size = n * stride;
+            *map_key = MAP_KEY.with(|v| *v.borrow());
+
+            for i in 0..n {
+                core::ptr::write(
+                    (map as *mut u8).add(i * stride) as *mut MemoryDescriptor,
+                    MemoryDescriptor {
+                        r#type: MemoryType::ConventionalMemory as u32,
+                        physical_start: 0x1000 * i as u64,
+                        virtual_start: 0,
+                        number_of_pages: 1,
+                        attribute: 0,
+                    },
+                );
+            }
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn exit_boot_services_racy(
+        image: crate::base::Handle,
+        map_key: usize,
+    ) -> crate::base::Status {
+        EXITS.with(|v| v.borrow_mut().push((image as usize, map_key)));
+
+        // The memory map changes once, right before the first exit.
+        MAP_KEY.with(|v| {
+            let mut v = v.borrow_mut();
+            if map_key == 0 {
+                *v = 1;
+            }
+            if map_key == *v {
+                crate::base::Status::SUCCESS
+            } else {
+                crate::base::Status::INVALID_PARAMETER
+            }
+        })
+    }}
+
+    #[test]
+    fn exit_boot_services_retry() {
+        let mut bs = crate::mock::boot_services();
+        bs.get_memory_map = get_memory_map_keyed;
+        bs.exit_boot_services = exit_boot_services_racy;
+        let image = 0x10 as crate::base::Handle;
+
+        let mut small = [MemoryDescriptor {
+            r#type: 0,
+            physical_start: 0,
+            virtual_start: 0,
+            number_of_pages: 0,
+            attribute: 0,
+        }; 2];
+        assert_eq!(
+            exit_boot_services(&bs, image, &mut small).err(),
+            Some(crate::base::Status::BUFFER_TOO_SMALL),
+        );
+        assert!(EXITS.with(|v| v.borrow().is_empty()));
+
+        let mut buffer = [small[0]; 8];
+        let map = exit_boot_services(&bs, image, &mut buffer).unwrap();
+        assert_eq!(EXITS.with(|v| v.borrow().clone()), [(0x10, 0), (0x10, 1)]);
+        assert_eq!(map.map_key, 1);
+        assert_eq!(
+            map.descriptors()
+                .map(|v| v.physical_start)
+                .collect::<Vec<_>>(),
+            [0x0000, 0x1000, 0x2000],
+        );
+
+        // A second failure is not retried again.
+        MAP_KEY.with(|v| *v.borrow_mut() = 0);
+        EXITS.with(|v| v.borrow_mut().clear());
+        bs.exit_boot_services = exit_boot_services_always_fails;
+        assert_eq!(
+            exit_boot_services(&bs, image, &mut buffer).err(),
+            Some(crate::base::Status::INVALID_PARAMETER),
+        );
+        assert_eq!(EXITS.with(|v| v.borrow().clone()), [(0x10, 0); 2]);
+    }
+
+    eficall! {fn exit_boot_services_always_fails(
+        image: crate::base::Handle,
+        map_key: usize,
+    ) -> crate::base::Status {
+        EXITS.with(|v| v.borrow_mut().push((image as usize, map_key)));
+        crate::base::Status::INVALID_PARAMETER
+    }}
+
+    eficall! {fn calculate_crc32_soft(
+        data: *mut core::ffi::c_void,
+        size: usize,
+        crc: *mut u32,
+    ) -> crate::base::Status {
+        if size == 0 {
+            return crate::base::Status::INVALID_PARAMETER;
+        }
+
+        unsafe { *crc = super::crc32(core::slice::from_raw_parts(data as *const u8, size)) };
+        crate::base::Status::SUCCESS
+    }}
+
+    static HANDLE_PROTOCOLS: [crate::base::Guid; 2] = [
+        graphics_output::PROTOCOL_GUID,
+        simple_text_output::PROTOCOL_GUID,
+    ];
+
+    eficall! {fn protocols_per_handle_pool(
+        handle: crate::base::Handle,
+        buffer: *mut *mut *mut crate::base::Guid,
+        count: *mut usize,
+    ) -> crate::base::Status {
+        if handle.is_null() {
+            return crate::base::Status::INVALID_PARAMETER;
+        }
+
+        let bs = crate::mock::boot_services_with_allocator();
+        let mut p: *mut core::ffi::c_void = core::ptr::null_mut();
+        let size = HANDLE_PROTOCOLS.len() * core::mem::size_of::<*mut crate::base::Guid>();
+
+        (bs.allocate_pool)(MemoryType::BootServicesData, size, &mut p);
+        unsafe {
+            let p = p as *mut *mut crate::base::Guid;
+            for (i, v) in HANDLE_PROTOCOLS.iter().enumerate() {
+                *p.add(i) = v as *const crate::base::Guid as *mut crate::base::Guid;
+            }
+            *buffer = p;
+            *count = HANDLE_PROTOCOLS.len();
+        }
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn notify(_event: crate::base::Event, _context: *mut core::ffi::c_void) {}}
+
+    std::thread_local! {
+        static EVENTS: RefCell<Vec<(u32, crate::base::Tpl, usize)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn create_event_ex(
+        r#type: u32,
+        notify_tpl: crate::base::Tpl,
+        _notify: EventNotify,
+        context: *const core::ffi::c_void,
+        group: *const crate::base::Guid,
+        event: *mut crate::base::Event,
+    ) -> crate::base::Status {
+        EVENTS.with(|v| v.borrow_mut().push((r#type, notify_tpl, context as usize)));
+
+        if unsafe { *group } != EVENT_GROUP_READY_TO_BOOT {
+            return crate::base::Status::INVALID_PARAMETER;
+        }
+
+        unsafe { *event = 0x20 as crate::base::Event };
+        crate::base::Status::SUCCESS
+    }}
+
+    eficall! {fn monotonic_count(count: *mut u64) -> crate::base::Status {
+        COUNT.with(|v| {
+            let mut v = v.borrow_mut();
+            if *v == u64::MAX {
+                return crate::base::Status::DEVICE_ERROR;
+            }
+            unsafe { *count = *v };
+            *v += 1;
+            crate::base::Status::SUCCESS
+        })
+    }}
+
+    eficall! {fn high_monotonic_count(count: *mut u32) -> crate::base::Status {
+        COUNT.with(|v| {
+            let mut v = v.borrow_mut();
+            *v = ((*v >> 32) + 1) << 32;
+            unsafe { *count = (*v >> 32) as u32 };
+            crate::base::Status::SUCCESS
+        })
+    }}
+
+    eficall! {fn stall_record(microseconds: usize) -> crate::base::Status {
+        STALLS.with(|v| v.borrow_mut().push(microseconds));
+        crate::base::Status::SUCCESS
+    }}
+
+    std::thread_local! {
+        static REGISTRATIONS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    eficall! {fn locate_protocol_gop(
+        guid: *mut crate::base::Guid,
+        registration: *mut core::ffi::c_void,
+        interface: *mut *mut core::ffi::c_void,
+    ) -> crate::base::Status {
+        REGISTRATIONS.with(|v| v.borrow_mut().push(registration as usize));
+
+        if unsafe { *guid } == graphics_output::PROTOCOL_GUID {
+            unsafe { *interface = 0x1000 as *mut core::ffi::c_void };
+            crate::base::Status::SUCCESS
+        } else {
+            crate::base::Status::NOT_FOUND
+        }
+    }}
+
+    #[test]
+    fn locate_protocol_by_type() {
+        let mut bs = crate::mock::boot_services();
+        bs.locate_protocol = locate_protocol_gop;
+
+        assert_eq!(
+            locate_protocol::<graphics_output::Protocol>(&bs),
+            Ok(0x1000 as *mut graphics_output::Protocol),
+        );
+        assert_eq!(
+            locate_protocol::<simple_text_output::Protocol>(&bs),
+            Err(crate::base::Status::NOT_FOUND),
+        );
+        assert_eq!(REGISTRATIONS.with(|v| v.take()), [0, 0]);
+    }
+
+    #[test]
+    fn flexible_array_layout() {
+        assert_eq!(core::mem::offset_of!(VariableAuthentication3CertId, id), 8);
+        assert_eq!(core::mem::offset_of!(VariableAuthentication, auth_info), 8);
+        assert_eq!(
+            core::mem::offset_of!(VariableAuthentication2, auth_info),
+            16
+        );
+        assert_eq!(
+            core::mem::offset_of!(VariableAuthentication3Nonce, nonce),
+            4
+        );
+        assert_eq!(core::mem::size_of::<VariableAuthentication3Nonce<16>>(), 20);
+        assert_eq!(
+            core::mem::offset_of!(CapsuleResultVariableFMP, capsule_file_name_and_target),
+            24,
+        );
+        assert_eq!(
+            core::mem::offset_of!(CapsuleResultVariableFMP<6>, capsule_file_name_and_target),
+            24,
+        );
+    }
+
+    #[test]
+    fn memory_attributes_table() {
+        // Header plus 2 descriptors with a stride of 48 bytes, as 8-byte words.
+        let mut table = [0u64; 2 + 2 * 6];
+
+        table[0] = 1 | (2 << 32);
+        table[1] = 48 | ((MEMORY_ATTRIBUTES_FLAGS_RT_FORWARD_CONTROL_FLOW_GUARD as u64) << 32);
+        table[2] = MemoryType::RuntimeServicesCode as u64;
+        table[3] = 0x1000;
+        table[5] = 1;
+        table[8] = MemoryType::RuntimeServicesData as u64;
+        table[9] = 0x2000;
+        table[11] = 2;
+
+        let mat = unsafe { &*(table.as_ptr() as *const MemoryAttributesTable) };
+        assert_eq!(mat.version, MEMORY_ATTRIBUTES_TABLE_VERSION);
+        assert_eq!(
+            mat.flags,
+            MEMORY_ATTRIBUTES_FLAGS_RT_FORWARD_CONTROL_FLOW_GUARD
+        );
+
+        let entries: Vec<(u32, u64, u64)> = unsafe { mat.entries() }
+            .map(|v| (v.r#type, v.physical_start, v.number_of_pages))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                (MemoryType::RuntimeServicesCode as u32, 0x1000, 1),
+                (MemoryType::RuntimeServicesData as u32, 0x2000, 2),
+            ],
+        );
+
+        // Descriptor sizes below the size of a descriptor are rejected.
+        let first = table[2..].as_ptr() as *const MemoryDescriptor;
+        assert_eq!(unsafe { MemoryDescriptors::new(first, 2, 8) }.count(), 0);
+    }
+
+    #[test]
+    fn configuration_tables() {
+        let entries = [
+            ConfigurationTable {
+                vendor_guid: MEMORY_ATTRIBUTES_TABLE_GUID,
+                vendor_table: 0x1000 as *mut core::ffi::c_void,
+            },
+            ConfigurationTable {
+                vendor_guid: PROPERTIES_TABLE_GUID,
+                vendor_table: 0x2000 as *mut core::ffi::c_void,
+            },
+        ];
+
+        let tables = unsafe { ConfigurationTables::new(entries.as_ptr(), entries.len()) };
+        assert_eq!(tables.len(), 2);
+        assert_eq!(
+            tables.find(&PROPERTIES_TABLE_GUID),
+            Some(0x2000 as *mut core::ffi::c_void),
+        );
+        assert_eq!(tables.find(&HARDWARE_ERROR_VARIABLE_GUID), None);
+
+        let guids: Vec<crate::base::Guid> = tables.into_iter().map(|v| v.vendor_guid).collect();
+        assert_eq!(guids, [MEMORY_ATTRIBUTES_TABLE_GUID, PROPERTIES_TABLE_GUID]);
+
+        let empty = unsafe { ConfigurationTables::new(core::ptr::null(), 5) };
+        assert!(empty.is_empty());
+        assert_eq!(empty.find(&PROPERTIES_TABLE_GUID), None);
+        assert_eq!(empty.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn stall_durations() {
+        let mut bs = crate::mock::boot_services();
+        bs.stall = stall_record;
+
+        stall(&bs, 17).unwrap();
+        stall_duration(&bs, core::time::Duration::from_millis(3)).unwrap();
+        stall_duration(&bs, core::time::Duration::from_nanos(1999)).unwrap();
+        stall_duration(&bs, core::time::Duration::MAX).unwrap();
+
+        STALLS.with(|v| assert_eq!(*v.borrow(), [17, 3000, 1, usize::MAX]));
+    }
+
+    #[test]
+    fn monotonic_counts() {
+        let mut bs = crate::mock::boot_services();
+        let mut rs = crate::mock::runtime_services();
+        bs.get_next_monotonic_count = monotonic_count;
+        rs.get_next_high_mono_count = high_monotonic_count;
+
+        assert_eq!(get_next_monotonic_count(&bs), Ok(0));
+        assert_eq!(get_next_monotonic_count(&bs), Ok(1));
+        assert_eq!(get_next_high_monotonic_count(&rs), Ok(1));
+        assert_eq!(get_next_monotonic_count(&bs), Ok(1 << 32));
+        assert_eq!(get_next_monotonic_count(&bs), Ok((1 << 32) + 1));
+
+        COUNT.with(|v| *v.borrow_mut() = u64::MAX);
+        assert_eq!(
+            get_next_monotonic_count(&bs),
+            Err(crate::base::Status::DEVICE_ERROR),
+        );
+    }
+
+    #[test]
+    fn event_groups() {
+        let mut bs = crate::mock::boot_services();
+        bs.create_event_ex = create_event_ex;
+
+        let context = 0x10 as *mut core::ffi::c_void;
+        assert_eq!(
+            create_event_in_group(
+                &bs,
+                &EVENT_GROUP_READY_TO_BOOT,
+                TPL_CALLBACK,
+                notify,
+                context
+            ),
+            Ok(0x20 as crate::base::Event),
+        );
+        assert_eq!(
+            create_event_in_group(
+                &bs,
+                &EVENT_GROUP_RESET_SYSTEM,
+                TPL_CALLBACK,
+                notify,
+                context
+            ),
+            Err(crate::base::Status::INVALID_PARAMETER),
+        );
+        assert_eq!(
+            EVENTS.with(|v| v.take()),
+            [(EVT_NOTIFY_SIGNAL, TPL_CALLBACK, 0x10); 2],
+        );
+    }
+
+    #[test]
+    fn crc32_check() {
+        let mut bs = crate::mock::boot_services();
+        bs.calculate_crc32 = calculate_crc32_soft;
+
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(&[0u8; 32]), 0x190a55ad);
+
+        assert_eq!(calculate_crc32(&bs, b"123456789"), Ok(0xcbf43926));
+        assert_eq!(
+            calculate_crc32(&bs, b""),
+            Err(crate::base::Status::INVALID_PARAMETER),
+        );
+    }
+
+    fn time(year: u16, month: u8, day: u8, hour: u8, minute: u8, timezone: i16) -> Time {
+        Time {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: 30,
+            pad1: 0,
+            nanosecond: 500,
+            timezone,
+            daylight: TIME_IN_DAYLIGHT,
+            pad2: 0,
+        }
+    }
+
+    fn utc(t: Time) -> Option<(u16, u8, u8, u8, u8)> {
+        t.to_utc().map(|v| {
+            assert_eq!(
+                (v.second, v.nanosecond, v.timezone, v.daylight),
+                (30, 500, 0, 0)
+            );
+            (v.year, v.month, v.day, v.hour, v.minute)
+        })
+    }
+
+    #[test]
+    fn time_to_utc() {
+        // Local time is UTC plus the timezone offset.
+        assert_eq!(utc(time(2024, 6, 15, 12, 0, 0)), Some((2024, 6, 15, 12, 0)));
+        assert_eq!(
+            utc(time(2024, 6, 15, 12, 0, 90)),
+            Some((2024, 6, 15, 10, 30))
+        );
+        assert_eq!(
+            utc(time(2024, 6, 15, 12, 0, -330)),
+            Some((2024, 6, 15, 17, 30))
+        );
+
+        // Month and year boundaries, with leap years.
+        assert_eq!(
+            utc(time(2024, 3, 1, 0, 30, 60)),
+            Some((2024, 2, 29, 23, 30))
+        );
+        assert_eq!(
+            utc(time(2023, 3, 1, 0, 30, 60)),
+            Some((2023, 2, 28, 23, 30))
+        );
+        assert_eq!(
+            utc(time(2000, 2, 28, 23, 0, -120)),
+            Some((2000, 2, 29, 1, 0))
+        );
+        assert_eq!(
+            utc(time(2100, 2, 28, 23, 0, -120)),
+            Some((2100, 3, 1, 1, 0))
+        );
+        assert_eq!(utc(time(2024, 4, 30, 23, 0, -60)), Some((2024, 5, 1, 0, 0)));
+        assert_eq!(utc(time(2024, 1, 1, 0, 0, 1)), Some((2023, 12, 31, 23, 59)));
+        assert_eq!(
+            utc(time(2023, 12, 31, 23, 59, -1440)),
+            Some((2024, 1, 1, 23, 59))
+        );
+
+        // Invalid input and results out of range.
+        assert_eq!(utc(time(2024, 6, 15, 12, 0, UNSPECIFIED_TIMEZONE)), None);
+        assert_eq!(utc(time(2024, 6, 15, 12, 0, 1441)), None);
+        assert_eq!(utc(time(2023, 2, 29, 12, 0, 0)), None);
+        assert_eq!(utc(time(2024, 13, 1, 12, 0, 0)), None);
+        assert_eq!(utc(time(2024, 6, 15, 24, 0, 0)), None);
+        assert_eq!(utc(time(1900, 1, 1, 0, 0, 60)), None);
+        assert_eq!(utc(time(9999, 12, 31, 23, 0, -60)), None);
+    }
+
+    #[test]
+    fn handle_protocols() {
+        let tracker = crate::mock::AllocationTracker::new();
+        let mut bs = crate::mock::boot_services_with_allocator();
+        bs.protocols_per_handle = protocols_per_handle_pool;
+
+        let protocols = unsafe { protocols_per_handle(&bs, 0x10 as crate::base::Handle) }.unwrap();
+        assert_eq!(protocols.len(), 2);
+        assert_eq!(
+            protocols.iter().collect::<Vec<_>>(),
+            [
+                graphics_output::PROTOCOL_GUID,
+                simple_text_output::PROTOCOL_GUID
+            ],
+        );
+        assert_eq!(tracker.leaks().len(), 1);
+
+        // Dropping the array releases the pool allocation.
+        drop(protocols);
+        assert!(tracker.leaks().is_empty());
+
+        assert_eq!(
+            unsafe { protocols_per_handle(&bs, core::ptr::null_mut()) }.err(),
+            Some(crate::base::Status::INVALID_PARAMETER),
+        );
+    }
+}